@@ -1,20 +1,39 @@
-use crate::{NciArrayIndexIter, NciIndex};
+use core::ops::{Bound, RangeBounds};
 
-#[derive(Debug, Clone, Copy, Default)]
-pub struct NciArray<'a, I, V> {
+use crate::{NciArrayEntryIter, NciArrayIndexIter, NciIndex, NciMemIdx, NciSegment};
+
+#[derive(Debug)]
+pub struct NciArray<'a, I, V, M = usize> {
     /// The user-defined index of the first element of each segment.
     /// Example: `segments_idx_begin[2] == 5` means the first element of the third segment has user-defined index 5.
     pub segments_idx_begin: &'a [I],
 
     /// The memory index of the first element of each segment.
     /// Example: `segments_mem_idx_begin[2] = 3` means the first element of the third segment is stored in memory index 3.
-    pub segments_mem_idx_begin: &'a [usize],
+    /// The type of the memory indices can be any [`NciMemIdx`] able to store them, e.g., `u16` for arrays with at most 65,536 values.
+    pub segments_mem_idx_begin: &'a [M],
 
     /// All the values stored in this array.
     pub values: &'a [V],
 }
 
-impl<I, V> NciArray<'_, I, V> {
+// Implemented manually, since deriving would require `I` and `V` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V, M> Clone for NciArray<'_, I, V, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V, M> Copy for NciArray<'_, I, V, M> {}
+
+impl<I, V, M> Default for NciArray<'_, I, V, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V, M> NciArray<'_, I, V, M> {
     pub const fn new() -> Self {
         Self {
             segments_idx_begin: &[],
@@ -24,7 +43,7 @@ impl<I, V> NciArray<'_, I, V> {
     }
 }
 
-impl<I: NciIndex, V> core::ops::Index<I> for NciArray<'_, I, V> {
+impl<I: NciIndex, V, M: NciMemIdx> core::ops::Index<I> for NciArray<'_, I, V, M> {
     type Output = V;
 
     fn index(&self, index: I) -> &Self::Output {
@@ -32,17 +51,77 @@ impl<I: NciIndex, V> core::ops::Index<I> for NciArray<'_, I, V> {
     }
 }
 
-impl<I: NciIndex, V> NciArray<'_, I, V> {
-    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> {
+impl<'a, I: NciIndex, V, M: NciMemIdx> NciArray<'a, I, V, M> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> core::slice::Iter<'a, V> {
         self.values.iter()
     }
 
-    pub fn indices(&self) -> impl ExactSizeIterator<Item = I> {
-        NciArrayIndexIter::new(self)
+    pub fn indices(&self) -> NciArrayIndexIter<'a, I, M> {
+        NciArrayIndexIter::new(
+            self.segments_idx_begin,
+            self.segments_mem_idx_begin,
+            self.values.len(),
+        )
+    }
+
+    pub fn entries(&self) -> NciArrayEntryIter<'a, I, V, M> {
+        NciArrayEntryIter::new(self.indices(), self.values)
+    }
+
+    /// Returns the segments of the array, i.e., its contiguous runs of entries, in ascending order.
+    pub fn segments(
+        &self,
+    ) -> impl ExactSizeIterator<Item = NciSegment<'a, I, V>> + DoubleEndedIterator + use<'a, I, V, M>
+    {
+        let array = *self;
+        (0..self.segments_idx_begin.len()).map(move |segment| array.segment(segment))
     }
 
-    pub fn entries(&self) -> impl ExactSizeIterator<Item = (I, &V)> {
-        self.indices().zip(self.values())
+    /// Returns the entries with indices within the given range, in ascending order.
+    pub fn range(&self, range: impl RangeBounds<I>) -> NciArrayEntryIter<'a, I, V, M> {
+        let front = match range.start_bound() {
+            Bound::Included(start) => self.lower_bound(*start),
+            Bound::Excluded(start) => start.next().and_then(|start| self.lower_bound(start)),
+            Bound::Unbounded => self.first_entry_of_segment(0),
+        };
+        let back = match range.end_bound() {
+            Bound::Included(end) => end.next().and_then(|end| self.lower_bound(end)),
+            Bound::Excluded(end) => self.lower_bound(*end),
+            Bound::Unbounded => None,
+        };
+        // The range ends before the entry at `back`, so the last entry in it is the one preceding `back`.
+        let (back_mem_idx, back_segment) = back.map_or(
+            (
+                self.values.len(),
+                self.segments_idx_begin.len().saturating_sub(1),
+            ),
+            |back| {
+                let back_segment =
+                    if back.mem_idx == self.segments_mem_idx_begin[back.segment].to_usize() {
+                        back.segment.saturating_sub(1)
+                    } else {
+                        back.segment
+                    };
+                (back.mem_idx, back_segment)
+            },
+        );
+        let indices = NciArrayIndexIter::from_positions(
+            self.segments_idx_begin,
+            self.segments_mem_idx_begin,
+            front,
+            back_mem_idx,
+            back_segment,
+        );
+        NciArrayEntryIter::new(indices, self.values)
     }
 
     pub fn has_entry(&self, index: I) -> bool {
@@ -52,35 +131,183 @@ impl<I: NciIndex, V> NciArray<'_, I, V> {
         })
     }
 
-    pub fn get(&self, index: I) -> Option<&V> {
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.mem_idx(index)
+            .map(|element_mem_idx| &self.values[element_mem_idx])
+    }
+
+    /// Returns the value of each of the given indices, which have to be sorted in ascending order.
+    /// Instead of a full binary search per index, the search for the segment of each index gallops forward
+    /// from the segment of the previous index, which is faster if there are many indices.
+    /// If the indices are not sorted, the results are unspecified, but no panic occurs.
+    pub fn get_many_sorted<J: IntoIterator<Item = I>>(
+        &self,
+        indices: J,
+    ) -> impl Iterator<Item = Option<&'a V>> + use<'a, I, V, M, J> {
+        let array = *self;
+        let segment_count = self.segments_idx_begin.len();
+        // The candidate segment of the previous index, which the candidate segments of further indices cannot precede
+        let mut first_segment = 0;
+        indices.into_iter().map(move |index| {
+            // Double the distance to the first segment until a segment beginning after the index is found
+            let mut bound = 1;
+            while first_segment + bound < segment_count
+                && array.segments_idx_begin[first_segment + bound] <= index
+            {
+                bound *= 2;
+            }
+            let segments = (first_segment + bound / 2)..(first_segment + bound).min(segment_count);
+            let segment = array.find_candidate_segment_in(index, segments)?;
+            first_segment = segment;
+            array
+                .mem_idx_in_segment(segment, index)
+                .map(|element_mem_idx| &array.values[element_mem_idx])
+        })
+    }
+
+    /// Returns the memory index of the element with the given index, if there is one.
+    pub(crate) fn mem_idx(&self, index: I) -> Option<usize> {
+        let segment = self.find_candidate_segment(index)?;
+        self.mem_idx_in_segment(segment, index)
+    }
+
+    /// Returns the memory index of the element with the given index, if the given segment contains it.
+    pub(crate) fn mem_idx_in_segment(&self, segment: usize, index: I) -> Option<usize> {
+        let distance = self.segments_idx_begin[segment].distance(index)?;
+        if distance >= self.segment_len(segment) {
+            return None;
+        }
+        Some(self.segments_mem_idx_begin[segment].to_usize() + distance)
+    }
+
+    /// Returns the entry with the greatest index less than or equal to `index`, if there is one.
+    pub fn floor(&self, index: I) -> Option<(I, &'a V)> {
+        self.entry_before(
+            index
+                .next()
+                .and_then(|next_index| self.lower_bound(next_index)),
+        )
+    }
+
+    /// Returns the entry with the least index greater than or equal to `index`, if there is one.
+    pub fn ceil(&self, index: I) -> Option<(I, &'a V)> {
+        self.lower_bound(index)
+            .map(|position| self.entry_at(&position))
+    }
+
+    /// Returns the entry with the greatest index less than `index`, if there is one.
+    pub fn predecessor(&self, index: I) -> Option<(I, &'a V)> {
+        self.entry_before(self.lower_bound(index))
+    }
+
+    /// Returns the entry with the least index greater than `index`, if there is one.
+    pub fn successor(&self, index: I) -> Option<(I, &'a V)> {
+        let next_index = index.next()?;
+        self.lower_bound(next_index)
+            .map(|position| self.entry_at(&position))
+    }
+
+    fn entry_at(&self, position: &EntryPosition<I>) -> (I, &'a V) {
+        (position.index, &self.values[position.mem_idx])
+    }
+
+    /// Returns the entry preceding the one at `position`, or the last entry if `position` is `None`.
+    fn entry_before(&self, position: Option<EntryPosition<I>>) -> Option<(I, &'a V)> {
+        let (segment, mem_idx) = match position {
+            Some(position)
+                if position.mem_idx == self.segments_mem_idx_begin[position.segment].to_usize() =>
+            {
+                (position.segment.checked_sub(1)?, position.mem_idx - 1)
+            }
+            Some(position) => (position.segment, position.mem_idx - 1),
+            None => (
+                self.segments_idx_begin.len().checked_sub(1)?,
+                self.values.len().checked_sub(1)?,
+            ),
+        };
+        let segment_idx_begin = self.segments_idx_begin[segment];
+        let index =
+            segment_idx_begin.advance(mem_idx - self.segments_mem_idx_begin[segment].to_usize())?;
+        Some((index, &self.values[mem_idx]))
+    }
+
+    /// Returns the position of the first entry with an index greater than or equal to `index`, if there is one.
+    pub(crate) fn lower_bound(&self, index: I) -> Option<EntryPosition<I>> {
         if let Some(segment) = self.find_candidate_segment(index) {
-            let distance = self.segments_idx_begin[segment].distance(index)?;
-            if distance >= self.segment_len(segment) {
-                return None;
+            let distance = self.segments_idx_begin[segment].distance(index);
+            if let Some(distance) = distance
+                && distance < self.segment_len(segment)
+            {
+                return Some(EntryPosition {
+                    segment,
+                    mem_idx: self.segments_mem_idx_begin[segment].to_usize() + distance,
+                    index,
+                });
             }
-            let element_mem_idx = self.segments_mem_idx_begin[segment] + distance;
-            Some(&self.values[element_mem_idx])
+            self.first_entry_of_segment(segment + 1)
         } else {
-            None
+            self.first_entry_of_segment(0)
         }
     }
 
+    /// Returns the position of the first entry of the given segment, if the segment exists.
+    fn first_entry_of_segment(&self, segment: usize) -> Option<EntryPosition<I>> {
+        Some(EntryPosition {
+            segment,
+            mem_idx: self.segments_mem_idx_begin.get(segment)?.to_usize(),
+            index: *self.segments_idx_begin.get(segment)?,
+        })
+    }
+
     /// Returns the segment that potentially contains the given index.
-    fn find_candidate_segment(&self, index: I) -> Option<usize> {
-        let candidate_segment_plus_one = self
-            .segments_idx_begin
+    pub(crate) fn find_candidate_segment(&self, index: I) -> Option<usize> {
+        self.find_candidate_segment_in(index, 0..self.segments_idx_begin.len())
+    }
+
+    /// Returns the segment that potentially contains the given index,
+    /// given that this segment (if there is one) is known to be within the given range of segments.
+    /// Panics in case the range exceeds the number of segments.
+    pub(crate) fn find_candidate_segment_in(
+        &self,
+        index: I,
+        segments: core::ops::Range<usize>,
+    ) -> Option<usize> {
+        let first_segment = segments.start;
+        let candidate_segment_offset_plus_one = self.segments_idx_begin[segments]
             .partition_point(|segment_idx_begin| index.ge(segment_idx_begin));
-        candidate_segment_plus_one.checked_sub(1)
+        (first_segment + candidate_segment_offset_plus_one).checked_sub(1)
+    }
+
+    /// Returns the `i`-th segment.
+    /// Panics in case there are fewer than `i + 1` segments.
+    fn segment(&self, segment: usize) -> NciSegment<'a, I, V> {
+        let start = self.segments_idx_begin[segment];
+        let mem_idx_begin = self.segments_mem_idx_begin[segment].to_usize();
+        let len = self.segment_len(segment);
+        NciSegment {
+            start,
+            end_inclusive: start.advance(len.saturating_sub(1)).unwrap_or(start),
+            values: &self.values[mem_idx_begin..mem_idx_begin + len],
+        }
     }
 
     /// Returns the length of the `i`-th segment.
     /// Panics in case there are fewer than `i + 1` segments.
-    fn segment_len(&self, segment: usize) -> usize {
-        let mem_idx_begin = self.segments_mem_idx_begin[segment];
-        let mem_idx_end = *self
+    pub(crate) fn segment_len(&self, segment: usize) -> usize {
+        let mem_idx_begin = self.segments_mem_idx_begin[segment].to_usize();
+        let mem_idx_end = self
             .segments_mem_idx_begin
             .get(segment + 1)
-            .unwrap_or(&self.values.len());
+            .map_or(self.values.len(), |next_segment_mem_idx_begin| {
+                next_segment_mem_idx_begin.to_usize()
+            });
         mem_idx_end - mem_idx_begin
     }
 }
+
+/// The position of an entry in an array.
+pub(crate) struct EntryPosition<I> {
+    pub segment: usize,
+    pub mem_idx: usize,
+    pub index: I,
+}
@@ -0,0 +1,223 @@
+use alloc::vec::Vec;
+
+use crate::{NciArray, NciArrayMut, NciIndex, NciMemIdx};
+
+/// An owned, heap-backed counterpart of [`NciArray`], which can be built at runtime.
+///
+/// All read-only functionality of [`NciArray`] is available through the view returned by [`NciArrayBuf::as_array`],
+/// and mutable access to the values through [`NciArrayBuf::as_array_mut`].
+/// Implementing `Deref` is not possible, since `NciArray` is itself a view storing references to the data.
+/// Unlike for `NciArray`, the memory indices are always stored as `usize`.
+#[derive(Debug, Clone)]
+pub struct NciArrayBuf<I, V> {
+    segments_idx_begin: Vec<I>,
+    segments_mem_idx_begin: Vec<usize>,
+    values: Vec<V>,
+}
+
+impl<I, V> Default for NciArrayBuf<I, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V> NciArrayBuf<I, V> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: Vec::new(),
+            segments_mem_idx_begin: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns a borrowed view of the array.
+    pub fn as_array(&self) -> NciArray<'_, I, V> {
+        NciArray {
+            segments_idx_begin: &self.segments_idx_begin,
+            segments_mem_idx_begin: &self.segments_mem_idx_begin,
+            values: &self.values,
+        }
+    }
+
+    /// Returns a view of the array that allows modifying its values.
+    pub fn as_array_mut(&mut self) -> NciArrayMut<'_, I, V> {
+        NciArrayMut {
+            segments_idx_begin: &self.segments_idx_begin,
+            segments_mem_idx_begin: &self.segments_mem_idx_begin,
+            values: &mut self.values,
+        }
+    }
+}
+
+impl<I: NciIndex, V> NciArrayBuf<I, V> {
+    /// Creates an array from the given entries, which do not need to be sorted.
+    /// If an index occurs multiple times, the last value for it is kept.
+    pub fn from_entries(entries: impl IntoIterator<Item = (I, V)>) -> Self {
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by_key(|(index, _value)| *index);
+
+        let mut array = Self::new();
+        let mut prv_index = None;
+        for (index, value) in entries {
+            if prv_index == Some(index) {
+                if let Some(last_value) = array.values.last_mut() {
+                    *last_value = value;
+                }
+                continue;
+            }
+            if prv_index.and_then(NciIndex::next) != Some(index) {
+                array.segments_idx_begin.push(index);
+                array.segments_mem_idx_begin.push(array.values.len());
+            }
+            array.values.push(value);
+            prv_index = Some(index);
+        }
+        array
+    }
+
+    pub fn get_mut(&mut self, index: I) -> Option<&mut V> {
+        let element_mem_idx = self.as_array().mem_idx(index)?;
+        Some(&mut self.values[element_mem_idx])
+    }
+
+    /// Inserts a value at the given index, returning the value previously stored there, if any.
+    /// Segments are extended, created, or merged as needed, so the layout is the same as if
+    /// the array had been created from all of its entries at once.
+    pub fn insert(&mut self, index: I, value: V) -> Option<V> {
+        let candidate_segment = self.as_array().find_candidate_segment(index);
+        if let Some(segment) = candidate_segment {
+            let distance = self.segments_idx_begin[segment].distance(index);
+            let segment_len = self.as_array().segment_len(segment);
+            if let Some(distance) = distance
+                && distance < segment_len
+            {
+                let mem_idx = self.segments_mem_idx_begin[segment] + distance;
+                return Some(core::mem::replace(&mut self.values[mem_idx], value));
+            }
+            if distance == Some(segment_len) {
+                // Extend the candidate segment at its end
+                let mem_idx = self.segments_mem_idx_begin[segment] + segment_len;
+                self.insert_value(segment + 1, mem_idx, value);
+                if self.segment_begins_at_next(segment + 1, index) {
+                    // The gap to the following segment is closed, so merge it into the candidate segment
+                    self.segments_idx_begin.remove(segment + 1);
+                    self.segments_mem_idx_begin.remove(segment + 1);
+                }
+                return None;
+            }
+        }
+
+        let next_segment = candidate_segment.map_or(0, |segment| segment + 1);
+        let mem_idx = self
+            .segments_mem_idx_begin
+            .get(next_segment)
+            .copied()
+            .unwrap_or(self.values.len());
+        if self.segment_begins_at_next(next_segment, index) {
+            // Extend the following segment at its beginning
+            self.segments_idx_begin[next_segment] = index;
+        } else {
+            self.segments_idx_begin.insert(next_segment, index);
+            self.segments_mem_idx_begin.insert(next_segment, mem_idx);
+        }
+        self.insert_value(next_segment + 1, mem_idx, value);
+        None
+    }
+
+    /// Removes the value at the given index and returns it, if there was one.
+    /// Segments are shrunk, removed, or split as needed.
+    pub fn remove(&mut self, index: I) -> Option<V> {
+        let segment = self.as_array().find_candidate_segment(index)?;
+        let segment_len = self.as_array().segment_len(segment);
+        let distance = self.segments_idx_begin[segment]
+            .distance(index)
+            .filter(|distance| *distance < segment_len)?;
+
+        let mem_idx = self.segments_mem_idx_begin[segment] + distance;
+        let value = self.values.remove(mem_idx);
+        for mem_idx_begin in &mut self.segments_mem_idx_begin[segment + 1..] {
+            *mem_idx_begin -= 1;
+        }
+
+        if segment_len == 1 {
+            self.segments_idx_begin.remove(segment);
+            self.segments_mem_idx_begin.remove(segment);
+        } else if distance + 1 < segment_len
+            && let Some(next_index) = index.next()
+        {
+            // `index.next()` always exists here, as the segment contains entries after `index`
+            if distance == 0 {
+                // The segment now begins at the following index
+                self.segments_idx_begin[segment] = next_index;
+            } else {
+                // Split the segment, with the entries after `index` forming a new segment
+                self.segments_idx_begin.insert(segment + 1, next_index);
+                self.segments_mem_idx_begin.insert(segment + 1, mem_idx);
+            }
+        }
+        Some(value)
+    }
+
+    /// Returns whether the given segment exists and begins directly after `index`.
+    fn segment_begins_at_next(&self, segment: usize, index: I) -> bool {
+        index
+            .next()
+            .is_some_and(|next_index| self.segments_idx_begin.get(segment) == Some(&next_index))
+    }
+
+    /// Inserts a value at the given memory index and shifts the memory indices of all segments
+    /// starting with `first_shifted_segment` accordingly.
+    fn insert_value(&mut self, first_shifted_segment: usize, mem_idx: usize, value: V) {
+        self.values.insert(mem_idx, value);
+        for mem_idx_begin in &mut self.segments_mem_idx_begin[first_shifted_segment..] {
+            *mem_idx_begin += 1;
+        }
+    }
+}
+
+impl<I: NciIndex, V> FromIterator<(I, V)> for NciArrayBuf<I, V> {
+    fn from_iter<T: IntoIterator<Item = (I, V)>>(iter: T) -> Self {
+        Self::from_entries(iter)
+    }
+}
+
+impl<I: NciIndex, V> core::ops::Index<I> for NciArrayBuf<I, V> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.as_array().get(index).unwrap()
+    }
+}
+
+impl<I: NciIndex, V> core::ops::IndexMut<I> for NciArrayBuf<I, V> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
+
+impl<'a, I, V> From<&'a NciArrayBuf<I, V>> for NciArray<'a, I, V> {
+    fn from(array: &'a NciArrayBuf<I, V>) -> Self {
+        array.as_array()
+    }
+}
+
+impl<'a, I, V> From<&'a mut NciArrayBuf<I, V>> for NciArrayMut<'a, I, V> {
+    fn from(array: &'a mut NciArrayBuf<I, V>) -> Self {
+        array.as_array_mut()
+    }
+}
+
+impl<I: Copy, V: Clone, M: NciMemIdx> From<NciArray<'_, I, V, M>> for NciArrayBuf<I, V> {
+    fn from(array: NciArray<'_, I, V, M>) -> Self {
+        Self {
+            segments_idx_begin: array.segments_idx_begin.to_vec(),
+            segments_mem_idx_begin: array
+                .segments_mem_idx_begin
+                .iter()
+                .map(|mem_idx| mem_idx.to_usize())
+                .collect(),
+            values: array.values.to_vec(),
+        }
+    }
+}
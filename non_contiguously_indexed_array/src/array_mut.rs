@@ -0,0 +1,104 @@
+use crate::{NciArray, NciArrayEntryIter, NciArrayIndexIter, NciIndex, NciMemIdx};
+
+/// A variant of [`NciArray`] that allows modifying its values in place.
+/// The segment layout is borrowed immutably, so it can be shared with other arrays.
+#[derive(Debug)]
+pub struct NciArrayMut<'a, I, V, M = usize> {
+    /// The user-defined index of the first element of each segment.
+    /// See [`NciArray::segments_idx_begin`].
+    pub segments_idx_begin: &'a [I],
+
+    /// The memory index of the first element of each segment.
+    /// See [`NciArray::segments_mem_idx_begin`].
+    pub segments_mem_idx_begin: &'a [M],
+
+    /// All the values stored in this array.
+    pub values: &'a mut [V],
+}
+
+impl<'a, I, V, M> NciArrayMut<'a, I, V, M> {
+    /// Creates an array with the same segment layout as `layout`, storing its values in `values`.
+    ///
+    /// # Panics
+    /// Panics if `values` and the values of `layout` differ in length.
+    pub fn with_layout<W>(layout: NciArray<'a, I, W, M>, values: &'a mut [V]) -> Self {
+        assert_eq!(layout.values.len(), values.len());
+        Self {
+            segments_idx_begin: layout.segments_idx_begin,
+            segments_mem_idx_begin: layout.segments_mem_idx_begin,
+            values,
+        }
+    }
+
+    /// Returns an immutable view of the array.
+    pub fn as_array(&self) -> NciArray<'_, I, V, M> {
+        NciArray {
+            segments_idx_begin: self.segments_idx_begin,
+            segments_mem_idx_begin: self.segments_mem_idx_begin,
+            values: self.values,
+        }
+    }
+}
+
+impl<'a, I: NciIndex, V, M: NciMemIdx> NciArrayMut<'a, I, V, M> {
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> core::slice::Iter<'_, V> {
+        self.values.iter()
+    }
+
+    pub fn values_mut(&mut self) -> core::slice::IterMut<'_, V> {
+        self.values.iter_mut()
+    }
+
+    pub fn indices(&self) -> NciArrayIndexIter<'a, I, M> {
+        NciArrayIndexIter::new(
+            self.segments_idx_begin,
+            self.segments_mem_idx_begin,
+            self.values.len(),
+        )
+    }
+
+    pub fn entries(&self) -> NciArrayEntryIter<'_, I, V, M> {
+        self.as_array().entries()
+    }
+
+    pub fn entries_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = (I, &mut V)> + DoubleEndedIterator {
+        self.indices().zip(self.values.iter_mut())
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.as_array().has_entry(index)
+    }
+
+    pub fn get(&self, index: I) -> Option<&V> {
+        self.as_array().get(index)
+    }
+
+    pub fn get_mut(&mut self, index: I) -> Option<&mut V> {
+        let element_mem_idx = self.as_array().mem_idx(index)?;
+        Some(&mut self.values[element_mem_idx])
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> core::ops::Index<I> for NciArrayMut<'_, I, V, M> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> core::ops::IndexMut<I> for NciArrayMut<'_, I, V, M> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
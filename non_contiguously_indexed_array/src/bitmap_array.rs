@@ -0,0 +1,123 @@
+use crate::{NciBitmapIndexIter, NciIndex};
+
+/// A variant of [`NciArray`](crate::NciArray) that stores which indices have an entry in a bitmap.
+/// This is more space-efficient than segments if most indices within the range of the array have an entry,
+/// but the gaps between them are scattered.
+#[derive(Debug)]
+pub struct NciBitmapArray<'a, I, V> {
+    /// The user-defined index corresponding to the first bit of the bitmap, or `None` if the array is empty.
+    pub idx_begin: Option<I>,
+
+    /// Whether there is an entry for each index, starting from `idx_begin`.
+    /// Example: bit 3 of `bitmap[2]` being set means there is an entry for the index `2 * 64 + 3` steps after `idx_begin`.
+    pub bitmap: &'a [u64],
+
+    /// The number of entries before each block of [`WORDS_PER_RANK_SAMPLE`](Self::WORDS_PER_RANK_SAMPLE) words of the bitmap.
+    /// Example: `rank_samples[2] == 500` means there are 500 entries before the first index of the word `bitmap[2 * WORDS_PER_RANK_SAMPLE]`.
+    pub rank_samples: &'a [usize],
+
+    /// All the values stored in this array.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `V` to implement the respective traits,
+// while only references to the values are stored.
+impl<I: Copy, V> Clone for NciBitmapArray<'_, I, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: Copy, V> Copy for NciBitmapArray<'_, I, V> {}
+
+impl<I, V> Default for NciBitmapArray<'_, I, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V> NciBitmapArray<'_, I, V> {
+    /// The number of words of the bitmap covered by each rank sample.
+    pub const WORDS_PER_RANK_SAMPLE: usize = 8;
+
+    pub const fn new() -> Self {
+        Self {
+            idx_begin: None,
+            bitmap: &[],
+            rank_samples: &[],
+            values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V> core::ops::Index<I> for NciBitmapArray<'_, I, V> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V> NciBitmapArray<'a, I, V> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> core::slice::Iter<'a, V> {
+        self.values.iter()
+    }
+
+    pub fn indices(&self) -> NciBitmapIndexIter<'a, I> {
+        NciBitmapIndexIter::new(self.idx_begin, self.bitmap, self.values.len())
+    }
+
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (I, &'a V)> + DoubleEndedIterator + use<'a, I, V> {
+        self.indices().zip(self.values.iter())
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.bit_position(index)
+            .is_some_and(|(word, bit)| self.bitmap[word] & (1 << bit) != 0)
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.mem_idx(index)
+            .map(|element_mem_idx| &self.values[element_mem_idx])
+    }
+
+    /// Returns the memory index of the element with the given index, if there is one.
+    fn mem_idx(&self, index: I) -> Option<usize> {
+        let (word, bit) = self.bit_position(index)?;
+        let word_bits = self.bitmap[word];
+        if word_bits & (1 << bit) == 0 {
+            return None;
+        }
+        let sampled_word = word - word % Self::WORDS_PER_RANK_SAMPLE;
+        let rank_before_word = self.rank_samples[word / Self::WORDS_PER_RANK_SAMPLE]
+            + self.bitmap[sampled_word..word]
+                .iter()
+                .map(|word_bits| word_bits.count_ones() as usize)
+                .sum::<usize>();
+        let rank_within_word = (word_bits & ((1 << bit) - 1)).count_ones() as usize;
+        Some(rank_before_word + rank_within_word)
+    }
+
+    /// Returns the word of the bitmap and the bit within that word corresponding to the given index,
+    /// if the bitmap covers the index.
+    fn bit_position(&self, index: I) -> Option<(usize, u32)> {
+        let idx_begin = self.idx_begin?;
+        if index < idx_begin {
+            return None;
+        }
+        let distance = idx_begin.distance(index)?;
+        let word = distance / 64;
+        (word < self.bitmap.len()).then_some((word, (distance % 64) as u32))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{NciArray, NciIndex, NciMemIdx};
+
+/// A variant of [`NciArray`] with a precomputed bucket table that bounds the search for the segment
+/// potentially containing an index to a few segments.
+/// Each bucket covers `2^bucket_shift` consecutive indices, starting from the first index of the array.
+///
+/// Lookups via [`get`](Self::get) and [`has_entry`](Self::has_entry) use the bucket table,
+/// while all other functionality of the underlying array is available via `Deref`.
+#[derive(Debug)]
+pub struct NciBucketArray<'a, I, V, M = usize> {
+    /// The underlying array.
+    pub array: NciArray<'a, I, V, M>,
+
+    /// The binary logarithm of the number of consecutive indices covered by each bucket.
+    /// Example: `bucket_shift == 4` means the first bucket covers the 16 indices starting from the first index of the array.
+    pub bucket_shift: u32,
+
+    /// The segment that potentially contains the first index of each bucket.
+    /// Indices after the last bucket are considered to be part of the last bucket.
+    /// Example: `bucket_segments[2] == 5` means the third bucket's first index is either in
+    /// or after the sixth segment, but before the seventh segment.
+    pub bucket_segments: &'a [usize],
+}
+
+// Implemented manually, since deriving would require `I` and `V` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V, M> Clone for NciBucketArray<'_, I, V, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V, M> Copy for NciBucketArray<'_, I, V, M> {}
+
+impl<I, V, M> Default for NciBucketArray<'_, I, V, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V, M> NciBucketArray<'_, I, V, M> {
+    pub const fn new() -> Self {
+        Self {
+            array: NciArray::new(),
+            bucket_shift: 0,
+            bucket_segments: &[],
+        }
+    }
+}
+
+impl<'a, I, V, M> core::ops::Deref for NciBucketArray<'a, I, V, M> {
+    type Target = NciArray<'a, I, V, M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.array
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> core::ops::Index<I> for NciBucketArray<'_, I, V, M> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V, M: NciMemIdx> NciBucketArray<'a, I, V, M> {
+    pub fn has_entry(&self, index: I) -> bool {
+        self.mem_idx(index).is_some()
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.mem_idx(index)
+            .map(|element_mem_idx| &self.array.values[element_mem_idx])
+    }
+
+    /// Returns the memory index of the element with the given index, if there is one.
+    fn mem_idx(&self, index: I) -> Option<usize> {
+        let segment = self.find_candidate_segment(index)?;
+        self.array.mem_idx_in_segment(segment, index)
+    }
+
+    /// Returns the segment that potentially contains the given index.
+    fn find_candidate_segment(&self, index: I) -> Option<usize> {
+        let first_idx = *self.array.segments_idx_begin.first()?;
+        if index < first_idx {
+            return None;
+        }
+        // Indices too far from the first index to have their distance represented as `usize` are in the last bucket
+        let bucket = first_idx.distance(index).map_or(usize::MAX, |distance| {
+            distance.checked_shr(self.bucket_shift).unwrap_or(0)
+        });
+        let first_segment = self
+            .bucket_segments
+            .get(bucket)
+            .or(self.bucket_segments.last())
+            .map_or(0, |bucket_segment| *bucket_segment);
+        let last_segment = bucket
+            .checked_add(1)
+            .and_then(|next_bucket| self.bucket_segments.get(next_bucket))
+            .map_or(
+                self.array.segments_idx_begin.len() - 1,
+                |next_bucket_segment| *next_bucket_segment,
+            );
+        self.array
+            .find_candidate_segment_in(index, first_segment..last_segment + 1)
+    }
+}
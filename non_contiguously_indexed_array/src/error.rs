@@ -0,0 +1,73 @@
+/// An invariant of the segment layout of an array that is violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NciLayoutError {
+    /// `segments_idx_begin` and `segments_mem_idx_begin` differ in length.
+    SegmentCountMismatch {
+        segments_idx_begin_len: usize,
+        segments_mem_idx_begin_len: usize,
+    },
+
+    /// There are values, but no segments.
+    MissingSegments,
+
+    /// The first segment does not begin at memory index 0.
+    FirstMemIdxNotZero { mem_idx: usize },
+
+    /// The memory index of the segment is not less than the number of values.
+    MemIdxOutOfBounds { segment: usize },
+
+    /// The memory index of the segment is not greater than that of the previous segment,
+    /// i.e., the previous segment would be empty.
+    UnsortedMemIdx { segment: usize },
+
+    /// The last index of the segment would exceed the maximum possible index.
+    SegmentOverflow { segment: usize },
+
+    /// The index of the segment is not greater than the last index of the previous segment.
+    UnsortedIdx { segment: usize },
+
+    /// The segment begins directly after the last index of the previous segment,
+    /// so the two segments should be merged.
+    AdjacentSegments { segment: usize },
+}
+
+impl core::fmt::Display for NciLayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SegmentCountMismatch {
+                segments_idx_begin_len,
+                segments_mem_idx_begin_len,
+            } => write!(
+                f,
+                "segments_idx_begin has {segments_idx_begin_len} elements, but segments_mem_idx_begin has {segments_mem_idx_begin_len}"
+            ),
+            Self::MissingSegments => write!(f, "there are values, but no segments"),
+            Self::FirstMemIdxNotZero { mem_idx } => {
+                write!(
+                    f,
+                    "the first segment begins at memory index {mem_idx} instead of 0"
+                )
+            }
+            Self::MemIdxOutOfBounds { segment } => {
+                write!(f, "segment {segment} begins past the end of the values")
+            }
+            Self::UnsortedMemIdx { segment } => write!(
+                f,
+                "segment {segment} does not begin at a greater memory index than the previous segment"
+            ),
+            Self::SegmentOverflow { segment } => {
+                write!(f, "segment {segment} exceeds the maximum possible index")
+            }
+            Self::UnsortedIdx { segment } => write!(
+                f,
+                "segment {segment} does not begin at a greater index than the end of the previous segment"
+            ),
+            Self::AdjacentSegments { segment } => write!(
+                f,
+                "segment {segment} begins directly after the previous segment and should be merged with it"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for NciLayoutError {}
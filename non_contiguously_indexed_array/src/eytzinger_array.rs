@@ -0,0 +1,118 @@
+use crate::{NciEytzingerIndexIter, NciIndex, NciMemIdx};
+
+/// A variant of [`NciArray`](crate::NciArray) that stores its segments in Eytzinger order,
+/// i.e., as a complete binary search tree in breadth-first order.
+/// This makes lookups more cache-friendly for arrays with many segments.
+#[derive(Debug)]
+pub struct NciEytzingerArray<'a, I, V, M = usize> {
+    /// The user-defined index of the first element of each segment, in Eytzinger order.
+    /// Example: `segments_idx_begin[0]` is the first index of the middle segment.
+    pub segments_idx_begin: &'a [I],
+
+    /// The memory index of the first element of each segment, in the same order as `segments_idx_begin`.
+    pub segments_mem_idx_begin: &'a [M],
+
+    /// All the values stored in this array, in ascending order of their indices.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `I` and `V` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V, M> Clone for NciEytzingerArray<'_, I, V, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V, M> Copy for NciEytzingerArray<'_, I, V, M> {}
+
+impl<I, V, M> Default for NciEytzingerArray<'_, I, V, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V, M> NciEytzingerArray<'_, I, V, M> {
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: &[],
+            segments_mem_idx_begin: &[],
+            values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> core::ops::Index<I> for NciEytzingerArray<'_, I, V, M> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V, M: NciMemIdx> NciEytzingerArray<'a, I, V, M> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> core::slice::Iter<'a, V> {
+        self.values.iter()
+    }
+
+    pub fn indices(&self) -> NciEytzingerIndexIter<'a, I, M> {
+        NciEytzingerIndexIter::new(
+            self.segments_idx_begin,
+            self.segments_mem_idx_begin,
+            self.values.len(),
+        )
+    }
+
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (I, &'a V)> + DoubleEndedIterator + use<'a, I, V, M> {
+        self.indices().zip(self.values.iter())
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.mem_idx(index).is_some()
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.mem_idx(index)
+            .map(|element_mem_idx| &self.values[element_mem_idx])
+    }
+
+    /// Returns the memory index of the element with the given index, if there is one.
+    fn mem_idx(&self, index: I) -> Option<usize> {
+        let (candidate_node, next_node) = self.find_candidate_node(index);
+        let candidate_segment = candidate_node.checked_sub(1)?;
+        let distance = self.segments_idx_begin[candidate_segment].distance(index)?;
+        let mem_idx_begin = self.segments_mem_idx_begin[candidate_segment].to_usize();
+        let mem_idx_end = next_node
+            .checked_sub(1)
+            .map_or(self.values.len(), |next_segment| {
+                self.segments_mem_idx_begin[next_segment].to_usize()
+            });
+        (distance < mem_idx_end - mem_idx_begin).then_some(mem_idx_begin + distance)
+    }
+
+    /// Returns the (1-based) tree nodes of the segment that potentially contains the given index
+    /// and of the segment following it, with 0 representing the absence of such a segment.
+    fn find_candidate_node(&self, index: I) -> (usize, usize) {
+        let mut node = 1;
+        while let Some(segment_idx_begin) = self.segments_idx_begin.get(node - 1) {
+            node = 2 * node + usize::from(index.ge(segment_idx_begin));
+        }
+        // The search path ends with a number of steps to the left after the last step to the right.
+        // The node of the last step to the right is the last segment beginning at or before `index`,
+        // while the node of the last step to the left is the first segment beginning after `index`.
+        let candidate_node = node >> (node.trailing_zeros() + 1);
+        let next_node = node >> (node.trailing_ones() + 1);
+        (candidate_node, next_node)
+    }
+}
@@ -1,3 +1,10 @@
+use core::fmt;
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
 pub trait NciIndex: Ord + Copy {
     /// Return the next index after this one, or `None` if this is the maximum possible index.
     fn next(self) -> Option<Self>;
@@ -5,10 +12,77 @@ pub trait NciIndex: Ord + Copy {
     /// Return the distance between `self` and `other`.
     /// If the distance is greater than `usize::MAX`, return `None`.
     fn distance(self, other: Self) -> Option<usize>;
+
+    /// Return the index `n` steps after this one, or `None` if there is no such index.
+    /// The default implementation calls `next` `n` times, so implementing a faster way is recommended.
+    fn advance(self, n: usize) -> Option<Self> {
+        let mut index = self;
+        for _ in 0..n {
+            index = index.next()?;
+        }
+        Some(index)
+    }
+
+    /// Writes this index as a Rust expression, e.g., for the Rust codegen of `NciArrayBuilder`.
+    /// The default implementation uses the `Debug` representation,
+    /// which is a valid expression for primitive integers, `char`, and arrays of them.
+    fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self: fmt::Debug,
+    {
+        write!(f, "{self:?}")
+    }
+}
+
+/// An index type with a least and a greatest index, which is required for all but the first element
+/// of tuples used as indices, as `next` has to wrap around from `MAX` to `MIN` for them.
+///
+/// The bounds do not have to be those of the underlying type. For example, a newtype `struct Column(u16)`
+/// deriving `NciIndex` can declare `MIN` as `Column(0)` and `MAX` as `Column(9)`,
+/// so that `(row, Column(9)).next()` is `(row + 1, Column(0))`.
+pub trait NciBoundedIndex: NciIndex {
+    /// The least index.
+    const MIN: Self;
+
+    /// The greatest index.
+    /// All indices from `MIN` to `MAX` have to be reachable from `MIN` via `next`.
+    /// `MAX.next()` may return an index greater than `MAX`, which tuples treat like `None`.
+    const MAX: Self;
 }
 
+macro_rules! impl_bounded_index_trait_for_num {
+    ($($t:ty),*) => {
+        $(
+            impl NciBoundedIndex for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+
+impl_bounded_index_trait_for_num!(u8, u16, u32, u64, u128, usize);
+impl_bounded_index_trait_for_num!(i8, i16, i32, i64, i128, isize);
+impl_bounded_index_trait_for_num!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+impl_bounded_index_trait_for_num!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize
+);
+impl_bounded_index_trait_for_num!(char);
+
 macro_rules! impl_index_trait_for_primitive_num {
-    ($t:ty) => {
+    ($t:ty, $checked_add_usize:ident) => {
         impl NciIndex for $t {
             fn next(self) -> Option<Self> {
                 self.checked_add(1)
@@ -16,18 +90,348 @@ macro_rules! impl_index_trait_for_primitive_num {
             fn distance(self, other: Self) -> Option<usize> {
                 self.abs_diff(other).try_into().ok()
             }
+            fn advance(self, n: usize) -> Option<Self> {
+                self.$checked_add_usize(n.try_into().ok()?)
+            }
+        }
+    };
+}
+
+impl_index_trait_for_primitive_num!(u8, checked_add);
+impl_index_trait_for_primitive_num!(u16, checked_add);
+impl_index_trait_for_primitive_num!(u32, checked_add);
+impl_index_trait_for_primitive_num!(u64, checked_add);
+impl_index_trait_for_primitive_num!(u128, checked_add);
+
+impl_index_trait_for_primitive_num!(usize, checked_add);
+
+impl_index_trait_for_primitive_num!(i8, checked_add_unsigned);
+impl_index_trait_for_primitive_num!(i16, checked_add_unsigned);
+impl_index_trait_for_primitive_num!(i32, checked_add_unsigned);
+impl_index_trait_for_primitive_num!(i64, checked_add_unsigned);
+impl_index_trait_for_primitive_num!(i128, checked_add_unsigned);
+impl_index_trait_for_primitive_num!(isize, checked_add_unsigned);
+
+macro_rules! impl_index_trait_for_non_zero_unsigned_num {
+    ($t:ty) => {
+        impl NciIndex for $t {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+            fn distance(self, other: Self) -> Option<usize> {
+                self.get().abs_diff(other.get()).try_into().ok()
+            }
+            fn advance(self, n: usize) -> Option<Self> {
+                self.checked_add(n.try_into().ok()?)
+            }
+            fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "::core::num::{}::new({self}).unwrap()", stringify!($t))
+            }
         }
     };
 }
 
-impl_index_trait_for_primitive_num!(u8);
-impl_index_trait_for_primitive_num!(u16);
-impl_index_trait_for_primitive_num!(u32);
-impl_index_trait_for_primitive_num!(u64);
-impl_index_trait_for_primitive_num!(u128);
+impl_index_trait_for_non_zero_unsigned_num!(NonZeroU8);
+impl_index_trait_for_non_zero_unsigned_num!(NonZeroU16);
+impl_index_trait_for_non_zero_unsigned_num!(NonZeroU32);
+impl_index_trait_for_non_zero_unsigned_num!(NonZeroU64);
+impl_index_trait_for_non_zero_unsigned_num!(NonZeroU128);
+impl_index_trait_for_non_zero_unsigned_num!(NonZeroUsize);
+
+/// Signed non-zero integers are indexed contiguously, skipping 0. For example, `-1.next()` is `1`.
+macro_rules! impl_index_trait_for_non_zero_signed_num {
+    ($t:ty) => {
+        impl NciIndex for $t {
+            fn next(self) -> Option<Self> {
+                self.advance(1)
+            }
+            fn distance(self, other: Self) -> Option<usize> {
+                let distance = self.get().abs_diff(other.get());
+                // 0 is between the two indices if their signs differ
+                let distance = if self.is_negative() == other.is_negative() {
+                    distance
+                } else {
+                    distance - 1
+                };
+                distance.try_into().ok()
+            }
+            fn advance(self, n: usize) -> Option<Self> {
+                let index = self.get().checked_add_unsigned(n.try_into().ok()?)?;
+                let index = if self.is_negative() && index >= 0 {
+                    index.checked_add(1)?
+                } else {
+                    index
+                };
+                Self::new(index)
+            }
+            fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "::core::num::{}::new({self}).unwrap()", stringify!($t))
+            }
+        }
+    };
+}
+
+impl_index_trait_for_non_zero_signed_num!(NonZeroI8);
+impl_index_trait_for_non_zero_signed_num!(NonZeroI16);
+impl_index_trait_for_non_zero_signed_num!(NonZeroI32);
+impl_index_trait_for_non_zero_signed_num!(NonZeroI64);
+impl_index_trait_for_non_zero_signed_num!(NonZeroI128);
+impl_index_trait_for_non_zero_signed_num!(NonZeroIsize);
+
+/// The first surrogate code point, which is the first code point that is not a valid `char`.
+const SURROGATES_BEGIN: u32 = 0xD800;
+
+/// The number of surrogate code points, i.e., `0xD800..=0xDFFF`.
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// Returns the position of `c` among all valid `char`s, i.e., its code point excluding the preceding surrogates.
+const fn char_position(c: char) -> u32 {
+    let code_point = c as u32;
+    if code_point < SURROGATES_BEGIN {
+        code_point
+    } else {
+        code_point - SURROGATE_COUNT
+    }
+}
+
+/// Returns the `char` at the given position among all valid `char`s, if there is one.
+fn char_at_position(position: u32) -> Option<char> {
+    if position < SURROGATES_BEGIN {
+        char::from_u32(position)
+    } else {
+        char::from_u32(position.checked_add(SURROGATE_COUNT)?)
+    }
+}
+
+/// `char`s are indexed by their code point, skipping the surrogate code points `0xD800..=0xDFFF`,
+/// as they are not valid `char`s. For example, `'\u{D7FF}'.next()` is `'\u{E000}'`.
+impl NciIndex for char {
+    fn next(self) -> Option<Self> {
+        char_at_position(char_position(self) + 1)
+    }
+
+    fn distance(self, other: Self) -> Option<usize> {
+        char_position(self)
+            .abs_diff(char_position(other))
+            .try_into()
+            .ok()
+    }
+
+    fn advance(self, n: usize) -> Option<Self> {
+        char_at_position(char_position(self).checked_add(n.try_into().ok()?)?)
+    }
+}
+
+/// IP addresses are indexed by their numeric value, e.g., `10.0.0.255.next()` is `10.0.1.0`.
+impl NciIndex for Ipv4Addr {
+    fn next(self) -> Option<Self> {
+        self.to_bits().next().map(Self::from_bits)
+    }
+
+    fn distance(self, other: Self) -> Option<usize> {
+        self.to_bits().distance(other.to_bits())
+    }
+
+    fn advance(self, n: usize) -> Option<Self> {
+        self.to_bits().advance(n).map(Self::from_bits)
+    }
+
+    fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.octets();
+        write!(f, "::core::net::Ipv4Addr::new({a}, {b}, {c}, {d})")
+    }
+}
+
+impl NciIndex for Ipv6Addr {
+    fn next(self) -> Option<Self> {
+        self.to_bits().next().map(Self::from_bits)
+    }
+
+    fn distance(self, other: Self) -> Option<usize> {
+        self.to_bits().distance(other.to_bits())
+    }
+
+    fn advance(self, n: usize) -> Option<Self> {
+        self.to_bits().advance(n).map(Self::from_bits)
+    }
+
+    fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::core::net::Ipv6Addr::from_bits({:#x})", self.to_bits())
+    }
+}
+
+/// Byte arrays are indexed as big-endian unsigned integers, which matches their lexicographic ordering.
+/// For example, `[0, 255].next()` is `[1, 0]`.
+impl<const N: usize> NciIndex for [u8; N] {
+    fn next(self) -> Option<Self> {
+        self.advance(1)
+    }
+
+    fn distance(self, other: Self) -> Option<usize> {
+        let (low, high) = if self <= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // Big-endian subtraction, starting at the least significant byte
+        let mut difference = [0; N];
+        let mut borrow = false;
+        for ((difference_byte, high_byte), low_byte) in
+            difference.iter_mut().zip(high).zip(low).rev()
+        {
+            let (byte_difference, borrow_1) = high_byte.overflowing_sub(low_byte);
+            let (byte_difference, borrow_2) = byte_difference.overflowing_sub(u8::from(borrow));
+            *difference_byte = byte_difference;
+            borrow = borrow_1 || borrow_2;
+        }
+        difference.iter().try_fold(0usize, |distance, byte| {
+            distance.checked_mul(256)?.checked_add(usize::from(*byte))
+        })
+    }
+
+    fn advance(self, n: usize) -> Option<Self> {
+        let mut index = self;
+        // Big-endian addition, starting at the least significant byte
+        let mut carry = n;
+        for byte in index.iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+            let sum = usize::from(*byte) + carry % 256;
+            *byte = (sum % 256) as u8;
+            carry = carry / 256 + sum / 256;
+        }
+        if carry == 0 { Some(index) } else { None }
+    }
+}
+
+impl NciBoundedIndex for Ipv4Addr {
+    const MIN: Self = Self::UNSPECIFIED;
+    const MAX: Self = Self::BROADCAST;
+}
+
+impl NciBoundedIndex for Ipv6Addr {
+    const MIN: Self = Self::UNSPECIFIED;
+    const MAX: Self = Self::from_bits(u128::MAX);
+}
+
+impl<const N: usize> NciBoundedIndex for [u8; N] {
+    const MIN: Self = [u8::MIN; N];
+    const MAX: Self = [u8::MAX; N];
+}
+
+/// Returns the number of indices from `MIN` to `MAX`, or `None` if it is greater than `usize::MAX`.
+fn bounded_index_count<I: NciBoundedIndex>() -> Option<usize> {
+    I::MIN.distance(I::MAX)?.checked_add(1)
+}
+
+/// Pairs are indexed lexicographically, i.e., like the digits of a number,
+/// with the second element ranging from `B::MIN` to `B::MAX` for each value of the first element.
+/// For example, `(1, u8::MAX).next()` is `(2, 0)`.
+/// Indices with a second element outside of that range yield wrong results.
+/// The elements have to implement `Debug` to be written as Rust expressions,
+/// as the `Self: Debug` bound of [`NciIndex::fmt_rust_expr`] does not imply `Debug` for the elements.
+impl<A: NciIndex + fmt::Debug, B: NciBoundedIndex + fmt::Debug> NciIndex for (A, B) {
+    fn next(self) -> Option<Self> {
+        let (a, b) = self;
+        b.next()
+            .filter(|b| *b <= B::MAX)
+            .map_or_else(|| Some((a.next()?, B::MIN)), |b| Some((a, b)))
+    }
+
+    fn distance(self, other: Self) -> Option<usize> {
+        let ((low_a, low_b), (high_a, high_b)) = if self <= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if low_a == high_a {
+            return low_b.distance(high_b);
+        }
+        // From `low` to the first index with `high_a`, and from there to `high`.
+        // Every part is computed separately, as `bounded_index_count` might overflow even if the sum does not.
+        let low_a_remaining = low_b.distance(B::MAX)?.checked_add(1)?;
+        let full_a_count = low_a.distance(high_a)? - 1;
+        let full_a_distance = if full_a_count == 0 {
+            0
+        } else {
+            full_a_count.checked_mul(bounded_index_count::<B>()?)?
+        };
+        let high_a_distance = B::MIN.distance(high_b)?;
+        low_a_remaining
+            .checked_add(full_a_distance)?
+            .checked_add(high_a_distance)
+    }
+
+    fn advance(self, n: usize) -> Option<Self> {
+        let (a, b) = self;
+        if let Some(b) = b.advance(n).filter(|b| *b <= B::MAX) {
+            return Some((a, b));
+        }
+        // As `b` cannot be advanced by `n` within the bounds, the distance to `B::MAX` is less than `n`.
+        let remaining = n - b.distance(B::MAX)? - 1;
+        let (full_a_count, b_distance) = bounded_index_count::<B>()
+            .map_or((0, remaining), |count| {
+                (remaining / count, remaining % count)
+            });
+        Some((a.advance(full_a_count + 1)?, B::MIN.advance(b_distance)?))
+    }
+
+    fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (a, b) = self;
+        f.write_str("(")?;
+        a.fmt_rust_expr(f)?;
+        f.write_str(", ")?;
+        b.fmt_rust_expr(f)?;
+        f.write_str(")")
+    }
+}
+
+impl<A: NciBoundedIndex + fmt::Debug, B: NciBoundedIndex + fmt::Debug> NciBoundedIndex for (A, B) {
+    const MIN: Self = (A::MIN, B::MIN);
+    const MAX: Self = (A::MAX, B::MAX);
+}
+
+/// Longer tuples are indexed like the equivalent nested pairs, e.g., `(a, b, c)` like `(a, (b, c))`.
+macro_rules! impl_index_trait_for_tuple {
+    (($first_t:ident $(, $t:ident)*), ($first_v:ident $(, $v:ident)*), $nested:tt) => {
+        impl<$first_t: NciIndex + fmt::Debug $(, $t: NciBoundedIndex + fmt::Debug)*> NciIndex
+            for ($first_t $(, $t)*)
+        {
+            fn next(self) -> Option<Self> {
+                let ($first_v $(, $v)*) = self;
+                NciIndex::next($nested).map(|$nested| ($first_v $(, $v)*))
+            }
+            fn distance(self, other: Self) -> Option<usize> {
+                let ($first_v $(, $v)*) = self;
+                let nested_self = $nested;
+                let ($first_v $(, $v)*) = other;
+                nested_self.distance($nested)
+            }
+            fn advance(self, n: usize) -> Option<Self> {
+                let ($first_v $(, $v)*) = self;
+                NciIndex::advance($nested, n).map(|$nested| ($first_v $(, $v)*))
+            }
+            fn fmt_rust_expr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($first_v $(, $v)*) = self;
+                f.write_str("(")?;
+                $first_v.fmt_rust_expr(f)?;
+                $(
+                    f.write_str(", ")?;
+                    $v.fmt_rust_expr(f)?;
+                )*
+                f.write_str(")")
+            }
+        }
+
+        impl<$first_t: NciBoundedIndex + fmt::Debug $(, $t: NciBoundedIndex + fmt::Debug)*> NciBoundedIndex
+            for ($first_t $(, $t)*)
+        {
+            const MIN: Self = ($first_t::MIN $(, $t::MIN)*);
+            const MAX: Self = ($first_t::MAX $(, $t::MAX)*);
+        }
+    };
+}
 
-impl_index_trait_for_primitive_num!(i8);
-impl_index_trait_for_primitive_num!(i16);
-impl_index_trait_for_primitive_num!(i32);
-impl_index_trait_for_primitive_num!(i64);
-impl_index_trait_for_primitive_num!(i128);
+impl_index_trait_for_tuple!((A, B, C), (a, b, c), (a, (b, c)));
+impl_index_trait_for_tuple!((A, B, C, D), (a, b, c, d), (a, (b, (c, d))));
@@ -0,0 +1,109 @@
+use crate::{NciArray, NciArrayIndexIter, NciIndex, NciMemIdx};
+
+/// A variant of [`NciArray`] that stores each distinct value only once.
+/// Instead of a value, each entry stores the id of its value, i.e., the memory index of the value within `unique_values`.
+/// This is more space-efficient than storing every value if the values are large and repeat often.
+#[derive(Debug)]
+pub struct NciInternedArray<'a, I, V, K = usize> {
+    /// The user-defined index of the first element of each segment.
+    /// See [`NciArray::segments_idx_begin`].
+    pub segments_idx_begin: &'a [I],
+
+    /// The memory index of the first element of each segment.
+    /// See [`NciArray::segments_mem_idx_begin`].
+    pub segments_mem_idx_begin: &'a [usize],
+
+    /// The value id of each element.
+    /// Example: `ids[2] == 5` means the value of the third element is `unique_values[5]`.
+    /// The type of the ids can be any [`NciMemIdx`] able to store them, e.g., `u8` for arrays with at most 256 distinct values.
+    pub ids: &'a [K],
+
+    /// All distinct values stored in this array.
+    pub unique_values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `I`, `V`, and `K` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V, K> Clone for NciInternedArray<'_, I, V, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V, K> Copy for NciInternedArray<'_, I, V, K> {}
+
+impl<I, V, K> Default for NciInternedArray<'_, I, V, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V, K> NciInternedArray<'_, I, V, K> {
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: &[],
+            segments_mem_idx_begin: &[],
+            ids: &[],
+            unique_values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V, K: NciMemIdx> core::ops::Index<I> for NciInternedArray<'_, I, V, K> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V, K: NciMemIdx> NciInternedArray<'a, I, V, K> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the value of each entry, in ascending order of their indices.
+    pub fn values(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &'a V> + DoubleEndedIterator + use<'a, I, V, K> {
+        let unique_values = self.unique_values;
+        self.ids.iter().map(move |id| &unique_values[id.to_usize()])
+    }
+
+    pub fn indices(&self) -> NciArrayIndexIter<'a, I> {
+        self.id_array().indices()
+    }
+
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (I, &'a V)> + DoubleEndedIterator + use<'a, I, V, K> {
+        let unique_values = self.unique_values;
+        self.id_array()
+            .entries()
+            .map(move |(index, id)| (index, &unique_values[id.to_usize()]))
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.id_array().has_entry(index)
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.id_array()
+            .get(index)
+            .map(|id| &self.unique_values[id.to_usize()])
+    }
+
+    /// Returns an array with the same segment layout, storing the value id of each entry.
+    const fn id_array(&self) -> NciArray<'a, I, K> {
+        NciArray {
+            segments_idx_begin: self.segments_idx_begin,
+            segments_mem_idx_begin: self.segments_mem_idx_begin,
+            values: self.ids,
+        }
+    }
+}
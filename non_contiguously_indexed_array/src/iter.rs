@@ -1,84 +1,661 @@
-use crate::{NciArray, NciIndex};
+use crate::{EntryPosition, NciIndex, NciMemIdx};
 
-pub struct NciArrayIndexIterData<'a, I> {
-    current_idx: I,
-    current_mem_idx: usize,
-    remaining_idx_begin: &'a [I],
-    remaining_mem_idx_begin: &'a [usize],
-    mem_idx_end: core::num::NonZero<usize>,
+/// Iterator over the indices of (a range of) the entries of an array, in ascending order.
+#[derive(Debug, Clone)]
+pub struct NciArrayIndexIter<'a, I, M = usize> {
+    segments_idx_begin: &'a [I],
+    segments_mem_idx_begin: &'a [M],
+    /// The index of the next element from the front, or `None` if the iterator is exhausted.
+    front_idx: Option<I>,
+    front_mem_idx: usize,
+    front_segment: usize,
+    /// The memory index after the next element from the back.
+    back_mem_idx: usize,
+    /// The segment containing the next element from the back.
+    back_segment: usize,
 }
 
-pub enum NciArrayIndexIter<'a, I> {
-    NonEmpty(NciArrayIndexIterData<'a, I>),
-    Empty,
-}
-
-impl<'a, I: NciIndex> NciArrayIndexIter<'a, I> {
-    pub fn new<V>(arr: &'a NciArray<'_, I, V>) -> Self {
-        #[allow(clippy::option_if_let_else)] // Using map_or as suggested makes it unreadable
-        if let Ok(mem_idx_end) = core::num::NonZero::try_from(arr.values.len()) {
+impl<'a, I: NciIndex, M: NciMemIdx> NciArrayIndexIter<'a, I, M> {
+    /// Creates an iterator over the indices of all elements.
+    pub(crate) fn new(
+        segments_idx_begin: &'a [I],
+        segments_mem_idx_begin: &'a [M],
+        values_len: usize,
+    ) -> Self {
+        let front = if values_len == 0 {
+            None
+        } else {
             // This assert improves performance by having a single panic check instead of
             // having separate panic checks for each of the indexing operations below.
-            assert!(!arr.segments_idx_begin.is_empty() && !arr.segments_mem_idx_begin.is_empty());
-            NciArrayIndexIter::NonEmpty(NciArrayIndexIterData {
-                current_idx: arr.segments_idx_begin[0],
-                current_mem_idx: 0,
-                remaining_idx_begin: &arr.segments_idx_begin[1..],
-                remaining_mem_idx_begin: &arr.segments_mem_idx_begin[1..],
-                mem_idx_end,
+            assert!(!segments_idx_begin.is_empty() && !segments_mem_idx_begin.is_empty());
+            Some(EntryPosition {
+                segment: 0,
+                mem_idx: segments_mem_idx_begin[0].to_usize(),
+                index: segments_idx_begin[0],
             })
+        };
+        Self::from_positions(
+            segments_idx_begin,
+            segments_mem_idx_begin,
+            front,
+            values_len,
+            segments_idx_begin.len().saturating_sub(1),
+        )
+    }
+
+    /// Creates an iterator over the indices of the elements from `front` up to (excluding) memory index `back_mem_idx`,
+    /// with `back_segment` being the segment containing the element at memory index `back_mem_idx - 1`.
+    pub(crate) fn from_positions(
+        segments_idx_begin: &'a [I],
+        segments_mem_idx_begin: &'a [M],
+        front: Option<EntryPosition<I>>,
+        back_mem_idx: usize,
+        back_segment: usize,
+    ) -> Self {
+        let front = front.filter(|front| front.mem_idx < back_mem_idx);
+        Self {
+            segments_idx_begin,
+            segments_mem_idx_begin,
+            front_idx: front.as_ref().map(|front| front.index),
+            front_mem_idx: front.as_ref().map_or(back_mem_idx, |front| front.mem_idx),
+            front_segment: front.map_or(0, |front| front.segment),
+            back_mem_idx,
+            back_segment,
+        }
+    }
+
+    /// Advances the iterator such that the next element returned from the front
+    /// is the first remaining index greater than or equal to `index`.
+    /// Does nothing if the next element is already greater than or equal to `index`.
+    pub fn seek(&mut self, index: I) {
+        let Some(front_idx) = self.front_idx else {
+            return;
+        };
+        if index <= front_idx {
+            return;
+        }
+        // `index` is greater than the first index of the front segment, so the result is always at least `front_segment`
+        let segment = self.front_segment
+            + self.segments_idx_begin[self.front_segment + 1..=self.back_segment]
+                .partition_point(|segment_idx_begin| index.ge(segment_idx_begin));
+        let segment_mem_idx_begin = self.segments_mem_idx_begin[segment].to_usize();
+        let segment_mem_idx_end = if segment == self.back_segment {
+            self.back_mem_idx
+        } else {
+            self.segments_mem_idx_begin[segment + 1].to_usize()
+        };
+        let distance = self.segments_idx_begin[segment].distance(index);
+        if let Some(distance) = distance
+            && distance < segment_mem_idx_end - segment_mem_idx_begin
+        {
+            self.front_idx = Some(index);
+            self.front_mem_idx = segment_mem_idx_begin + distance;
+            self.front_segment = segment;
+        } else if segment == self.back_segment {
+            self.exhaust();
         } else {
-            NciArrayIndexIter::Empty
+            self.front_idx = Some(self.segments_idx_begin[segment + 1]);
+            self.front_mem_idx = segment_mem_idx_end;
+            self.front_segment = segment + 1;
         }
     }
+
+    /// Returns the memory index of the next element from the front, if there is one.
+    fn front_mem_idx(&self) -> Option<usize> {
+        self.front_idx.map(|_| self.front_mem_idx)
+    }
+
+    /// Returns the memory index of the next element from the back, if there is one.
+    fn back_mem_idx(&self) -> Option<usize> {
+        self.front_idx.map(|_| self.back_mem_idx - 1)
+    }
+
+    /// Returns the segment containing the remaining element with the given memory index.
+    fn remaining_segment_of(&self, mem_idx: usize) -> usize {
+        self.front_segment
+            + self.segments_mem_idx_begin[self.front_segment + 1..=self.back_segment]
+                .partition_point(|segment_mem_idx_begin| {
+                    mem_idx >= segment_mem_idx_begin.to_usize()
+                })
+    }
+
+    fn exhaust(&mut self) {
+        self.front_idx = None;
+        self.front_mem_idx = self.back_mem_idx;
+    }
 }
 
-impl<I: NciIndex> Iterator for NciArrayIndexIter<'_, I> {
+impl<I: NciIndex, M: NciMemIdx> Iterator for NciArrayIndexIter<'_, I, M> {
     type Item = I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self {
-            NciArrayIndexIter::NonEmpty(iter_data) => {
-                let result = iter_data.current_idx;
-                let next_mem_idx = iter_data.current_mem_idx + 1;
-                if next_mem_idx != iter_data.mem_idx_end.get() {
-                    iter_data.current_mem_idx = next_mem_idx;
-                    iter_data.current_idx = if let Some(next_segment_mem_idx) =
-                        iter_data.remaining_mem_idx_begin.first()
-                        && next_mem_idx == *next_segment_mem_idx
-                        && !iter_data.remaining_idx_begin.is_empty()
-                    {
-                        // Jump to next segment
-                        iter_data.remaining_mem_idx_begin.split_off_first();
-                        *iter_data.remaining_idx_begin.split_off_first().unwrap()
-                    } else {
-                        // If the data structure was properly constructed, `result.next()` should never yield `None` here.
-                        // Using `unwrap_or` here is a deliberate decision to avoid generating a panic handler
-                        // and also ensures that the iterator always returns exactly `self.len()` elements.
-                        result.next().unwrap_or(result)
-                    };
-                } else {
-                    *self = NciArrayIndexIter::Empty;
-                }
-                Some(result)
-            }
-            NciArrayIndexIter::Empty => None,
-        }
+        let result = self.front_idx?;
+        let next_mem_idx = self.front_mem_idx + 1;
+        self.front_mem_idx = next_mem_idx;
+        self.front_idx = if next_mem_idx == self.back_mem_idx {
+            None
+        } else if let Some(next_segment_mem_idx) =
+            self.segments_mem_idx_begin.get(self.front_segment + 1)
+            && next_mem_idx == next_segment_mem_idx.to_usize()
+            && let Some(next_segment_idx) = self.segments_idx_begin.get(self.front_segment + 1)
+        {
+            // Jump to next segment
+            self.front_segment += 1;
+            Some(*next_segment_idx)
+        } else {
+            // If the data structure was properly constructed, `result.next()` should never yield `None` here.
+            // Using `unwrap_or` here is a deliberate decision to avoid generating a panic handler
+            // and also ensures that the iterator always returns exactly `self.len()` elements.
+            Some(result.next().unwrap_or(result))
+        };
+        Some(result)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let remaining = self.len();
         (remaining, Some(remaining))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.exhaust();
+            return None;
+        }
+        if n > 0 {
+            let mem_idx = self.front_mem_idx + n;
+            let segment = self.remaining_segment_of(mem_idx);
+            let segment_idx_begin = self.segments_idx_begin[segment];
+            let distance = mem_idx - self.segments_mem_idx_begin[segment].to_usize();
+            self.front_idx = Some(
+                segment_idx_begin
+                    .advance(distance)
+                    .unwrap_or(segment_idx_begin),
+            );
+            self.front_mem_idx = mem_idx;
+            self.front_segment = segment;
+        }
+        self.next()
+    }
+}
+
+impl<I: NciIndex, M: NciMemIdx> DoubleEndedIterator for NciArrayIndexIter<'_, I, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let front_idx = self.front_idx?;
+        let mem_idx = self.back_mem_idx - 1;
+        if mem_idx == self.front_mem_idx {
+            self.front_idx = None;
+            return Some(front_idx);
+        }
+        self.back_mem_idx = mem_idx;
+
+        let segment_idx_begin = self.segments_idx_begin[self.back_segment];
+        let segment_mem_idx_begin = self.segments_mem_idx_begin[self.back_segment].to_usize();
+        if mem_idx == segment_mem_idx_begin {
+            self.back_segment = self.back_segment.saturating_sub(1);
+        }
+        // As in `next`, `unwrap_or` ensures that the iterator always returns exactly `self.len()` elements.
+        Some(
+            segment_idx_begin
+                .advance(mem_idx - segment_mem_idx_begin)
+                .unwrap_or(segment_idx_begin),
+        )
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.exhaust();
+            return None;
+        }
+        if n > 0 {
+            self.back_mem_idx -= n;
+            self.back_segment = self.remaining_segment_of(self.back_mem_idx - 1);
+        }
+        self.next_back()
+    }
+}
+
+impl<I: NciIndex, M: NciMemIdx> ExactSizeIterator for NciArrayIndexIter<'_, I, M> {
+    fn len(&self) -> usize {
+        if self.front_idx.is_some() {
+            self.back_mem_idx - self.front_mem_idx
+        } else {
+            0
+        }
+    }
+}
+
+impl<I: NciIndex, M: NciMemIdx> core::iter::FusedIterator for NciArrayIndexIter<'_, I, M> {}
+
+/// Iterator over (a range of) the entries of an array, in ascending order of their indices.
+#[derive(Debug)]
+pub struct NciArrayEntryIter<'a, I, V, M = usize> {
+    indices: NciArrayIndexIter<'a, I, M>,
+    values: &'a [V],
+}
+
+impl<'a, I: NciIndex, V, M: NciMemIdx> NciArrayEntryIter<'a, I, V, M> {
+    /// Creates an iterator over the entries with the indices yielded by `indices`,
+    /// with `values` being the values of the whole array.
+    pub(crate) const fn new(indices: NciArrayIndexIter<'a, I, M>, values: &'a [V]) -> Self {
+        Self { indices, values }
+    }
+
+    /// Advances the iterator such that the next entry returned from the front
+    /// is the first remaining entry with an index greater than or equal to `index`.
+    /// Does nothing if the index of the next entry is already greater than or equal to `index`.
+    pub fn seek(&mut self, index: I) {
+        self.indices.seek(index);
+    }
+}
+
+// Implemented manually, since deriving would require `V` to implement `Clone`.
+impl<I: NciIndex, V, M: NciMemIdx> Clone for NciArrayEntryIter<'_, I, V, M> {
+    fn clone(&self) -> Self {
+        Self {
+            indices: self.indices.clone(),
+            values: self.values,
+        }
+    }
+}
+
+impl<'a, I: NciIndex, V, M: NciMemIdx> Iterator for NciArrayEntryIter<'a, I, V, M> {
+    type Item = (I, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mem_idx = self.indices.front_mem_idx()?;
+        let index = self.indices.next()?;
+        Some((index, &self.values[mem_idx]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n > 0 {
+            self.indices.nth(n - 1)?;
+        }
+        self.next()
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> DoubleEndedIterator for NciArrayEntryIter<'_, I, V, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mem_idx = self.indices.back_mem_idx()?;
+        let index = self.indices.next_back()?;
+        Some((index, &self.values[mem_idx]))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n > 0 {
+            self.indices.nth_back(n - 1)?;
+        }
+        self.next_back()
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> ExactSizeIterator for NciArrayEntryIter<'_, I, V, M> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<I: NciIndex, V, M: NciMemIdx> core::iter::FusedIterator for NciArrayEntryIter<'_, I, V, M> {}
+
+/// Iterator over the indices of the entries of a [`NciBitmapArray`](crate::NciBitmapArray), in ascending order.
+#[derive(Debug, Clone)]
+pub struct NciBitmapIndexIter<'a, I> {
+    idx_begin: Option<I>,
+    bitmap: &'a [u64],
+    /// The word of the bitmap containing the next element from the front.
+    front_word: usize,
+    /// The bits of `front_word` that have not been returned from the front yet.
+    front_bits: u64,
+    /// The word of the bitmap containing the next element from the back.
+    back_word: usize,
+    /// The bits of `back_word` that have not been returned from the back yet.
+    back_bits: u64,
+    /// The number of remaining elements. As the front and the back of the iterator consume the bits in opposite order,
+    /// they never return the same element, even when sharing a word, as long as they stop after this many elements.
+    remaining: usize,
+}
+
+impl<'a, I: NciIndex> NciBitmapIndexIter<'a, I> {
+    /// Creates an iterator over the indices of all `len` elements.
+    pub(crate) fn new(idx_begin: Option<I>, bitmap: &'a [u64], len: usize) -> Self {
+        let back_word = bitmap.len().saturating_sub(1);
+        Self {
+            idx_begin,
+            bitmap,
+            front_word: 0,
+            front_bits: bitmap.first().copied().unwrap_or(0),
+            back_word,
+            back_bits: bitmap.get(back_word).copied().unwrap_or(0),
+            remaining: len,
+        }
+    }
+
+    /// Returns the index corresponding to the given bit, which is `None` only for invalid layouts.
+    fn index_at(&self, word: usize, bit: u32) -> Option<I> {
+        self.idx_begin?.advance(word * 64 + bit as usize)
+    }
+}
+
+impl<I: NciIndex> Iterator for NciBitmapIndexIter<'_, I> {
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.front_bits == 0 {
+            self.front_word += 1;
+            self.front_bits = self.bitmap[self.front_word];
+        }
+        let bit = self.front_bits.trailing_zeros();
+        self.front_bits &= self.front_bits - 1;
+        self.remaining -= 1;
+        self.index_at(self.front_word, bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: NciIndex> DoubleEndedIterator for NciBitmapIndexIter<'_, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.back_bits == 0 {
+            self.back_word -= 1;
+            self.back_bits = self.bitmap[self.back_word];
+        }
+        let bit = u64::BITS - 1 - self.back_bits.leading_zeros();
+        self.back_bits &= !(1 << bit);
+        self.remaining -= 1;
+        self.index_at(self.back_word, bit)
+    }
 }
 
-impl<I: NciIndex> ExactSizeIterator for NciArrayIndexIter<'_, I> {
+impl<I: NciIndex> ExactSizeIterator for NciBitmapIndexIter<'_, I> {
     fn len(&self) -> usize {
-        match self {
-            NciArrayIndexIter::NonEmpty(iter_data) => {
-                iter_data.mem_idx_end.get() - iter_data.current_mem_idx
+        self.remaining
+    }
+}
+
+impl<I: NciIndex> core::iter::FusedIterator for NciBitmapIndexIter<'_, I> {}
+
+/// Iterator over the indices of the entries of a [`NciSegmentLenArray`](crate::NciSegmentLenArray)
+/// or of a [`NciSet`](crate::NciSet), in ascending order.
+#[derive(Debug, Clone)]
+pub struct NciSegmentLenIndexIter<'a, I, L = u8> {
+    segments_idx_begin: &'a [I],
+    segments_len: &'a [L],
+    /// The segment containing the next element from the front.
+    front_segment: usize,
+    /// The distance of the next element from the front to the first element of `front_segment`.
+    front_distance: usize,
+    /// The segment containing the next element from the back.
+    back_segment: usize,
+    /// The distance after the next element from the back to the first element of `back_segment`.
+    back_distance: usize,
+    /// The number of remaining elements, which prevents the front and the back from overlapping.
+    remaining: usize,
+}
+
+impl<'a, I: NciIndex, L: Copy + Into<usize>> NciSegmentLenIndexIter<'a, I, L> {
+    /// Creates an iterator over the indices of all `len` elements.
+    pub(crate) fn new(segments_idx_begin: &'a [I], segments_len: &'a [L], len: usize) -> Self {
+        Self {
+            segments_idx_begin,
+            segments_len,
+            front_segment: 0,
+            front_distance: 0,
+            back_segment: segments_len.len().saturating_sub(1),
+            back_distance: segments_len.last().map_or(0, |len| (*len).into()),
+            remaining: len,
+        }
+    }
+
+    fn index_at(&self, segment: usize, distance: usize) -> I {
+        // As in `NciArrayIndexIter`, `unwrap_or` is used to avoid generating a panic handler.
+        let segment_idx_begin = self.segments_idx_begin[segment];
+        segment_idx_begin
+            .advance(distance)
+            .unwrap_or(segment_idx_begin)
+    }
+}
+
+impl<I: NciIndex, L: Copy + Into<usize>> Iterator for NciSegmentLenIndexIter<'_, I, L> {
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.front_distance >= self.segments_len[self.front_segment].into() {
+            self.front_segment += 1;
+            self.front_distance = 0;
+        }
+        let index = self.index_at(self.front_segment, self.front_distance);
+        self.front_distance += 1;
+        self.remaining -= 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: NciIndex, L: Copy + Into<usize>> DoubleEndedIterator for NciSegmentLenIndexIter<'_, I, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.back_distance == 0 {
+            self.back_segment -= 1;
+            self.back_distance = self.segments_len[self.back_segment].into();
+        }
+        self.back_distance -= 1;
+        self.remaining -= 1;
+        Some(self.index_at(self.back_segment, self.back_distance))
+    }
+}
+
+impl<I: NciIndex, L: Copy + Into<usize>> ExactSizeIterator for NciSegmentLenIndexIter<'_, I, L> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<I: NciIndex, L: Copy + Into<usize>> core::iter::FusedIterator
+    for NciSegmentLenIndexIter<'_, I, L>
+{
+}
+
+/// Iterator over the indices of the entries of a [`NciEytzingerArray`](crate::NciEytzingerArray), in ascending order.
+/// The segments are visited by an in-order traversal of the (1-based) tree nodes of the Eytzinger layout.
+#[derive(Debug, Clone)]
+pub struct NciEytzingerIndexIter<'a, I, M = usize> {
+    segments_idx_begin: &'a [I],
+    segments_mem_idx_begin: &'a [M],
+    /// The node of the segment containing the next element from the front.
+    front_node: usize,
+    /// The memory index of the next element from the front.
+    front_mem_idx: usize,
+    /// The node of the segment containing the next element from the back.
+    back_node: usize,
+    /// The memory index after the next element from the back.
+    back_mem_idx_end: usize,
+}
+
+impl<'a, I: NciIndex, M: NciMemIdx> NciEytzingerIndexIter<'a, I, M> {
+    /// Creates an iterator over the indices of all `len` elements.
+    pub(crate) fn new(
+        segments_idx_begin: &'a [I],
+        segments_mem_idx_begin: &'a [M],
+        len: usize,
+    ) -> Self {
+        let node_count = segments_idx_begin.len();
+        let mut front_node = 1;
+        while 2 * front_node <= node_count {
+            front_node *= 2;
+        }
+        let mut back_node = 1;
+        while 2 * back_node < node_count {
+            back_node = 2 * back_node + 1;
+        }
+        Self {
+            segments_idx_begin,
+            segments_mem_idx_begin,
+            front_node,
+            front_mem_idx: 0,
+            back_node,
+            back_mem_idx_end: len,
+        }
+    }
+
+    /// Returns the node following the given one in sorted order, or 0 if there is none.
+    const fn successor(&self, mut node: usize) -> usize {
+        if 2 * node < self.segments_idx_begin.len() {
+            node = 2 * node + 1;
+            while 2 * node <= self.segments_idx_begin.len() {
+                node *= 2;
+            }
+            node
+        } else {
+            node >> (node.trailing_ones() + 1)
+        }
+    }
+
+    /// Returns the node preceding the given one in sorted order, or 0 if there is none.
+    const fn predecessor(&self, mut node: usize) -> usize {
+        if 2 * node <= self.segments_idx_begin.len() {
+            node *= 2;
+            while 2 * node < self.segments_idx_begin.len() {
+                node = 2 * node + 1;
             }
-            NciArrayIndexIter::Empty => 0,
+            node
+        } else {
+            node >> (node.trailing_zeros() + 1)
         }
     }
+
+    fn mem_idx_begin(&self, node: usize) -> usize {
+        self.segments_mem_idx_begin[node - 1].to_usize()
+    }
+
+    fn index_at(&self, node: usize, mem_idx: usize) -> I {
+        // As in `NciArrayIndexIter`, `unwrap_or` is used to avoid generating a panic handler.
+        let segment_idx_begin = self.segments_idx_begin[node - 1];
+        segment_idx_begin
+            .advance(mem_idx - self.mem_idx_begin(node))
+            .unwrap_or(segment_idx_begin)
+    }
 }
+
+impl<I: NciIndex, M: NciMemIdx> Iterator for NciEytzingerIndexIter<'_, I, M> {
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front_mem_idx >= self.back_mem_idx_end {
+            return None;
+        }
+        loop {
+            let next_node = self.successor(self.front_node);
+            if next_node == 0 || self.mem_idx_begin(next_node) > self.front_mem_idx {
+                break;
+            }
+            self.front_node = next_node;
+        }
+        let index = self.index_at(self.front_node, self.front_mem_idx);
+        self.front_mem_idx += 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back_mem_idx_end.saturating_sub(self.front_mem_idx);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<I: NciIndex, M: NciMemIdx> DoubleEndedIterator for NciEytzingerIndexIter<'_, I, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front_mem_idx >= self.back_mem_idx_end {
+            return None;
+        }
+        self.back_mem_idx_end -= 1;
+        while self.mem_idx_begin(self.back_node) > self.back_mem_idx_end {
+            self.back_node = self.predecessor(self.back_node);
+        }
+        Some(self.index_at(self.back_node, self.back_mem_idx_end))
+    }
+}
+
+impl<I: NciIndex, M: NciMemIdx> ExactSizeIterator for NciEytzingerIndexIter<'_, I, M> {
+    fn len(&self) -> usize {
+        self.back_mem_idx_end.saturating_sub(self.front_mem_idx)
+    }
+}
+
+impl<I: NciIndex, M: NciMemIdx> core::iter::FusedIterator for NciEytzingerIndexIter<'_, I, M> {}
+
+/// Iterator over the entries of a [`NciRunArray`](crate::NciRunArray), in ascending order of their indices.
+#[derive(Debug)]
+pub struct NciRunEntryIter<'a, I, V> {
+    /// The indices of the entries, with the runs in place of segments.
+    indices: NciArrayIndexIter<'a, I>,
+    /// The value of each run.
+    values: &'a [V],
+}
+
+impl<'a, I: NciIndex, V> NciRunEntryIter<'a, I, V> {
+    /// Creates an iterator over the entries with the indices yielded by `indices`,
+    /// with `values` being the values of all runs.
+    pub(crate) const fn new(indices: NciArrayIndexIter<'a, I>, values: &'a [V]) -> Self {
+        Self { indices, values }
+    }
+}
+
+// Implemented manually, since deriving would require `V` to implement `Clone`.
+impl<I: NciIndex, V> Clone for NciRunEntryIter<'_, I, V> {
+    fn clone(&self) -> Self {
+        Self {
+            indices: self.indices.clone(),
+            values: self.values,
+        }
+    }
+}
+
+impl<'a, I: NciIndex, V> Iterator for NciRunEntryIter<'a, I, V> {
+    type Item = (I, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let run = self.indices.front_segment;
+        let index = self.indices.next()?;
+        Some((index, &self.values[run]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<I: NciIndex, V> DoubleEndedIterator for NciRunEntryIter<'_, I, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let run = self.indices.back_segment;
+        let index = self.indices.next_back()?;
+        Some((index, &self.values[run]))
+    }
+}
+
+impl<I: NciIndex, V> ExactSizeIterator for NciRunEntryIter<'_, I, V> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<I: NciIndex, V> core::iter::FusedIterator for NciRunEntryIter<'_, I, V> {}
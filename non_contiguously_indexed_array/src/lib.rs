@@ -1,10 +1,65 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod array;
 pub use array::*;
 
+mod array_mut;
+pub use array_mut::*;
+
+#[cfg(feature = "alloc")]
+mod array_buf;
+#[cfg(feature = "alloc")]
+pub use array_buf::*;
+
+mod error;
+pub use error::*;
+
+mod validate;
+
 mod index;
 pub use index::*;
 
+#[cfg(feature = "derive")]
+pub use non_contiguously_indexed_array_derive::NciIndex;
+
+mod mem_idx;
+pub use mem_idx::*;
+
+mod eytzinger_array;
+pub use eytzinger_array::*;
+
+mod bucket_array;
+pub use bucket_array::*;
+
+mod bitmap_array;
+pub use bitmap_array::*;
+
+mod run_array;
+pub use run_array::*;
+
+mod interned_array;
+pub use interned_array::*;
+
+mod segment_len_array;
+pub use segment_len_array::*;
+
+mod multi_array;
+pub use multi_array::*;
+
+mod str_array;
+pub use str_array::*;
+
+mod set;
+pub use set::*;
+
+mod range_map;
+pub use range_map::*;
+
+mod segment;
+pub use segment::*;
+
 mod iter;
-use iter::*;
+pub use iter::*;
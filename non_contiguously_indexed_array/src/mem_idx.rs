@@ -0,0 +1,21 @@
+/// An unsigned integer type that can store memory indices, i.e., positions within a slice.
+/// Using a smaller type than `usize` reduces the size of arrays storing many memory indices.
+pub trait NciMemIdx: Copy {
+    /// Return the memory index as `usize`.
+    fn to_usize(self) -> usize;
+}
+
+macro_rules! impl_mem_idx_trait_for_primitive_num {
+    ($t:ty) => {
+        impl NciMemIdx for $t {
+            fn to_usize(self) -> usize {
+                self as usize
+            }
+        }
+    };
+}
+
+impl_mem_idx_trait_for_primitive_num!(u8);
+impl_mem_idx_trait_for_primitive_num!(u16);
+impl_mem_idx_trait_for_primitive_num!(u32);
+impl_mem_idx_trait_for_primitive_num!(usize);
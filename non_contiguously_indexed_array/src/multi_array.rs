@@ -0,0 +1,121 @@
+use crate::{NciArray, NciArrayIndexIter, NciIndex, NciMemIdx};
+
+/// A variant of [`NciArray`] where each entry has any number of values, stored as a slice.
+/// The values of all entries are stored consecutively in a single slice,
+/// with each entry storing the memory index of its first value.
+/// For string values, see [`NciStrArray`](crate::NciStrArray), which stores them as a single `str` in the same way.
+#[derive(Debug)]
+pub struct NciMultiArray<'a, I, V, O = usize> {
+    /// The user-defined index of the first element of each segment.
+    /// See [`NciArray::segments_idx_begin`].
+    pub segments_idx_begin: &'a [I],
+
+    /// The memory index of the first element of each segment.
+    /// See [`NciArray::segments_mem_idx_begin`].
+    pub segments_mem_idx_begin: &'a [usize],
+
+    /// The memory index of the first value of each element, with the values of an element
+    /// ending where the values of the next element begin.
+    /// Example: `values_begin[2] == 5 && values_begin[3] == 7` means the values of the third element
+    /// are `values[5..7]`.
+    /// The type of the memory indices can be any [`NciMemIdx`] able to store them, e.g., `u16` for at most 65,536 values.
+    pub values_begin: &'a [O],
+
+    /// The values of all elements.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `I`, `V`, and `O` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V, O> Clone for NciMultiArray<'_, I, V, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V, O> Copy for NciMultiArray<'_, I, V, O> {}
+
+impl<I, V, O> Default for NciMultiArray<'_, I, V, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V, O> NciMultiArray<'_, I, V, O> {
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: &[],
+            segments_mem_idx_begin: &[],
+            values_begin: &[],
+            values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V, O: NciMemIdx> core::ops::Index<I> for NciMultiArray<'_, I, V, O> {
+    type Output = [V];
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V, O: NciMemIdx> NciMultiArray<'a, I, V, O> {
+    /// Returns the number of entries in the array, i.e., the number of indices with values.
+    pub const fn len(&self) -> usize {
+        self.values_begin.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values_begin.is_empty()
+    }
+
+    /// Returns the values of each entry, in ascending order of their indices.
+    pub fn values(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &'a [V]> + DoubleEndedIterator + use<'a, I, V, O> {
+        let array = *self;
+        (0..self.len()).map(move |mem_idx| array.values_of(mem_idx))
+    }
+
+    pub fn indices(&self) -> NciArrayIndexIter<'a, I> {
+        self.values_begin_array().indices()
+    }
+
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (I, &'a [V])> + DoubleEndedIterator + use<'a, I, V, O> {
+        self.indices().zip(self.values())
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.values_begin_array().has_entry(index)
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a [V]> {
+        self.values_begin_array()
+            .mem_idx(index)
+            .map(|mem_idx| self.values_of(mem_idx))
+    }
+
+    /// Returns the values of the element with the given memory index.
+    /// Panics in case there are fewer than `mem_idx + 1` elements.
+    fn values_of(&self, mem_idx: usize) -> &'a [V] {
+        let values_end = self
+            .values_begin
+            .get(mem_idx + 1)
+            .map_or(self.values.len(), |next_values_begin| {
+                next_values_begin.to_usize()
+            });
+        &self.values[self.values_begin[mem_idx].to_usize()..values_end]
+    }
+
+    /// Returns an array with the same segment layout, storing the memory index of the first value of each entry.
+    const fn values_begin_array(&self) -> NciArray<'a, I, O> {
+        NciArray {
+            segments_idx_begin: self.segments_idx_begin,
+            segments_mem_idx_begin: self.segments_mem_idx_begin,
+            values: self.values_begin,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use core::ops::RangeInclusive;
+
+use crate::NciIndex;
+
+/// A map from non-overlapping ranges of indices to values, where each range maps to a single value.
+/// Unlike in [`NciArray`](crate::NciArray), the end of each segment is stored explicitly,
+/// so a range can be longer than `usize::MAX` while still only requiring a single value.
+#[derive(Debug)]
+pub struct NciRangeMap<'a, I, V> {
+    /// The first index of each range.
+    /// Example: `ranges_idx_begin[2] == 5` means the third range begins with index 5.
+    pub ranges_idx_begin: &'a [I],
+
+    /// The last index of each range.
+    /// Example: `ranges_idx_end[2] == 7` means the third range ends with index 7 (inclusive).
+    pub ranges_idx_end: &'a [I],
+
+    /// The value of each range.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `I` and `V` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V> Clone for NciRangeMap<'_, I, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V> Copy for NciRangeMap<'_, I, V> {}
+
+impl<I, V> Default for NciRangeMap<'_, I, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V> NciRangeMap<'_, I, V> {
+    pub const fn new() -> Self {
+        Self {
+            ranges_idx_begin: &[],
+            ranges_idx_end: &[],
+            values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V> core::ops::Index<I> for NciRangeMap<'_, I, V> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V> NciRangeMap<'a, I, V> {
+    /// Returns the number of ranges in the map.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> core::slice::Iter<'a, V> {
+        self.values.iter()
+    }
+
+    /// Returns the ranges in the map in ascending order.
+    pub fn ranges(
+        &self,
+    ) -> impl ExactSizeIterator<Item = RangeInclusive<I>> + DoubleEndedIterator + use<'a, I, V>
+    {
+        self.ranges_idx_begin
+            .iter()
+            .zip(self.ranges_idx_end)
+            .map(|(range_idx_begin, range_idx_end)| *range_idx_begin..=*range_idx_end)
+    }
+
+    /// Returns the ranges in the map in ascending order, together with their values.
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (RangeInclusive<I>, &'a V)> + DoubleEndedIterator + use<'a, I, V>
+    {
+        self.ranges().zip(self.values)
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.find_range(index).is_some()
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.find_range(index).map(|range| &self.values[range])
+    }
+
+    /// Returns the range containing the given index, if there is one.
+    pub fn range_of(&self, index: I) -> Option<RangeInclusive<I>> {
+        self.find_range(index)
+            .map(|range| self.ranges_idx_begin[range]..=self.ranges_idx_end[range])
+    }
+
+    /// Returns the range containing the given index together with its value, if there is one.
+    pub fn get_range_value(&self, index: I) -> Option<(RangeInclusive<I>, &'a V)> {
+        self.find_range(index).map(|range| {
+            (
+                self.ranges_idx_begin[range]..=self.ranges_idx_end[range],
+                &self.values[range],
+            )
+        })
+    }
+
+    /// Returns the position of the range containing the given index, if there is one.
+    fn find_range(&self, index: I) -> Option<usize> {
+        let range = self
+            .ranges_idx_begin
+            .partition_point(|range_idx_begin| index.ge(range_idx_begin))
+            .checked_sub(1)?;
+        (index <= self.ranges_idx_end[range]).then_some(range)
+    }
+}
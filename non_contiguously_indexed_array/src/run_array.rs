@@ -0,0 +1,102 @@
+use crate::{NciArrayIndexIter, NciIndex, NciRunEntryIter};
+
+/// A variant of [`NciArray`](crate::NciArray) that stores a single value for each run of contiguous indices with equal values.
+/// This is more space-efficient than storing every value if the values form long runs.
+#[derive(Debug)]
+pub struct NciRunArray<'a, I, V> {
+    /// The user-defined index of the first element of each run.
+    /// Unlike segments, runs can directly follow each other.
+    /// Example: `runs_idx_begin[2] == 5` means the first element of the third run has user-defined index 5.
+    pub runs_idx_begin: &'a [I],
+
+    /// The number of elements before each run.
+    /// Example: `runs_entry_begin[2] == 3` means the first element of the third run is the fourth element of the array.
+    pub runs_entry_begin: &'a [usize],
+
+    /// The number of elements in this array.
+    pub len: usize,
+
+    /// The value of each run.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `I` and `V` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V> Clone for NciRunArray<'_, I, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V> Copy for NciRunArray<'_, I, V> {}
+
+impl<I, V> Default for NciRunArray<'_, I, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V> NciRunArray<'_, I, V> {
+    pub const fn new() -> Self {
+        Self {
+            runs_idx_begin: &[],
+            runs_entry_begin: &[],
+            len: 0,
+            values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V> core::ops::Index<I> for NciRunArray<'_, I, V> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V> NciRunArray<'a, I, V> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value of each run, i.e., every value once per run instead of once per entry.
+    pub fn run_values(&self) -> core::slice::Iter<'a, V> {
+        self.values.iter()
+    }
+
+    pub fn indices(&self) -> NciArrayIndexIter<'a, I> {
+        NciArrayIndexIter::new(self.runs_idx_begin, self.runs_entry_begin, self.len)
+    }
+
+    pub fn entries(&self) -> NciRunEntryIter<'a, I, V> {
+        NciRunEntryIter::new(self.indices(), self.values)
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.run(index).is_some()
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.run(index).map(|run| &self.values[run])
+    }
+
+    /// Returns the run containing the given index, if there is one.
+    fn run(&self, index: I) -> Option<usize> {
+        let run = self
+            .runs_idx_begin
+            .partition_point(|run_idx_begin| index.ge(run_idx_begin))
+            .checked_sub(1)?;
+        let run_entry_end = self
+            .runs_entry_begin
+            .get(run + 1)
+            .map_or(self.len, |next_run_entry_begin| *next_run_entry_begin);
+        let distance = self.runs_idx_begin[run].distance(index)?;
+        (distance < run_entry_end - self.runs_entry_begin[run]).then_some(run)
+    }
+}
@@ -0,0 +1,39 @@
+use core::ops::RangeInclusive;
+
+/// A contiguous run of entries of an [`NciArray`](crate::NciArray).
+#[derive(Debug, PartialEq, Eq)]
+pub struct NciSegment<'a, I, V> {
+    /// The index of the first entry in the segment.
+    pub start: I,
+
+    /// The index of the last entry in the segment.
+    pub end_inclusive: I,
+
+    /// The values of all entries in the segment.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `V` to implement the respective traits.
+impl<I: Copy, V> Clone for NciSegment<'_, I, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: Copy, V> Copy for NciSegment<'_, I, V> {}
+
+impl<I: Copy, V> NciSegment<'_, I, V> {
+    /// Returns the range of indices covered by the segment.
+    pub const fn range(&self) -> RangeInclusive<I> {
+        RangeInclusive::new(self.start, self.end_inclusive)
+    }
+
+    /// Returns the number of entries in the segment.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
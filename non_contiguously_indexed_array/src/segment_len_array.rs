@@ -0,0 +1,123 @@
+use crate::{NciIndex, NciSegmentLenIndexIter};
+
+/// A variant of [`NciArray`](crate::NciArray) that stores the length of each segment as a `u8`
+/// instead of the memory index of its first element, with the memory index of every
+/// [`SEGMENTS_PER_CHECKPOINT`](Self::SEGMENTS_PER_CHECKPOINT)-th segment stored as a checkpoint.
+/// As segment lengths are at most `u8::MAX`, continuous index ranges with more elements are split into multiple segments.
+/// This requires less space than `NciArray` if most segments are short, at the cost of slightly slower lookups.
+#[derive(Debug)]
+pub struct NciSegmentLenArray<'a, I, V> {
+    /// The user-defined index of the first element of each segment.
+    /// Unlike in `NciArray`, segments can directly follow each other, as longer segments have to be split.
+    /// Example: `segments_idx_begin[2] == 5` means the first element of the third segment has user-defined index 5.
+    pub segments_idx_begin: &'a [I],
+
+    /// The number of elements in each segment.
+    /// Example: `segments_len[2] == 3` means the third segment consists of 3 elements.
+    pub segments_len: &'a [u8],
+
+    /// The memory index of the first element of each block of
+    /// [`SEGMENTS_PER_CHECKPOINT`](Self::SEGMENTS_PER_CHECKPOINT) segments.
+    /// Example: `mem_idx_checkpoints[2] == 40` means the first element of the segment `2 * SEGMENTS_PER_CHECKPOINT`
+    /// is stored in memory index 40.
+    pub mem_idx_checkpoints: &'a [usize],
+
+    /// All the values stored in this array.
+    pub values: &'a [V],
+}
+
+// Implemented manually, since deriving would require `I` and `V` to implement the respective traits,
+// while only references to them are stored.
+impl<I, V> Clone for NciSegmentLenArray<'_, I, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, V> Copy for NciSegmentLenArray<'_, I, V> {}
+
+impl<I, V> Default for NciSegmentLenArray<'_, I, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, V> NciSegmentLenArray<'_, I, V> {
+    /// The number of segments covered by each memory index checkpoint.
+    pub const SEGMENTS_PER_CHECKPOINT: usize = 16;
+
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: &[],
+            segments_len: &[],
+            mem_idx_checkpoints: &[],
+            values: &[],
+        }
+    }
+}
+
+impl<I: NciIndex, V> core::ops::Index<I> for NciSegmentLenArray<'_, I, V> {
+    type Output = V;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, V> NciSegmentLenArray<'a, I, V> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> core::slice::Iter<'a, V> {
+        self.values.iter()
+    }
+
+    pub fn indices(&self) -> NciSegmentLenIndexIter<'a, I> {
+        NciSegmentLenIndexIter::new(
+            self.segments_idx_begin,
+            self.segments_len,
+            self.values.len(),
+        )
+    }
+
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (I, &'a V)> + DoubleEndedIterator + use<'a, I, V> {
+        self.indices().zip(self.values)
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.mem_idx(index).is_some()
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a V> {
+        self.mem_idx(index)
+            .map(|element_mem_idx| &self.values[element_mem_idx])
+    }
+
+    /// Returns the memory index of the element with the given index, if there is one.
+    fn mem_idx(&self, index: I) -> Option<usize> {
+        let segment = self
+            .segments_idx_begin
+            .partition_point(|segment_idx_begin| index.ge(segment_idx_begin))
+            .checked_sub(1)?;
+        let distance = self.segments_idx_begin[segment].distance(index)?;
+        if distance >= usize::from(self.segments_len[segment]) {
+            return None;
+        }
+        let checkpoint = segment / Self::SEGMENTS_PER_CHECKPOINT;
+        let checkpoint_segment = checkpoint * Self::SEGMENTS_PER_CHECKPOINT;
+        let segment_mem_idx_begin = self.mem_idx_checkpoints[checkpoint]
+            + self.segments_len[checkpoint_segment..segment]
+                .iter()
+                .map(|segment_len| usize::from(*segment_len))
+                .sum::<usize>();
+        Some(segment_mem_idx_begin + distance)
+    }
+}
@@ -0,0 +1,193 @@
+use core::ops::RangeInclusive;
+
+use crate::{NciIndex, NciSegmentLenIndexIter};
+
+/// A set of indices, stored like the indices of an [`NciArray`](crate::NciArray), but without any values.
+/// Instead of the memory index of its first element, the length of each segment is stored.
+#[derive(Debug)]
+pub struct NciSet<'a, I> {
+    /// The first index of each segment.
+    /// Example: `segments_idx_begin[2] == 5` means the third segment begins with index 5.
+    pub segments_idx_begin: &'a [I],
+
+    /// The number of indices in each segment.
+    /// Example: `segments_len[2] == 3` means the third segment consists of 3 indices.
+    pub segments_len: &'a [usize],
+}
+
+// Implemented manually, since deriving would require `I` to implement the respective traits,
+// while only references to it are stored.
+impl<I> Clone for NciSet<'_, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for NciSet<'_, I> {}
+
+impl<I> Default for NciSet<'_, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> NciSet<'_, I> {
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: &[],
+            segments_len: &[],
+        }
+    }
+}
+
+impl<'a, I: NciIndex> NciSet<'a, I> {
+    /// Returns the number of indices in the set.
+    /// This requires summing up the lengths of all segments, i.e., it takes time linear in the number of segments.
+    pub fn len(&self) -> usize {
+        self.segments_len.iter().sum()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.segments_idx_begin.is_empty()
+    }
+
+    pub fn contains(&self, index: I) -> bool {
+        self.segments_idx_begin
+            .partition_point(|segment_idx_begin| index.ge(segment_idx_begin))
+            .checked_sub(1)
+            .is_some_and(|segment| {
+                self.segments_idx_begin[segment]
+                    .distance(index)
+                    .is_some_and(|distance| distance < self.segments_len[segment])
+            })
+    }
+
+    /// Returns the indices in the set in ascending order.
+    /// Creating the iterator sums up the lengths of all segments, like [`Self::len`], so that the iterator knows its exact length.
+    pub fn iter(&self) -> NciSegmentLenIndexIter<'a, I, usize> {
+        NciSegmentLenIndexIter::new(self.segments_idx_begin, self.segments_len, self.len())
+    }
+
+    /// Returns the segments of the set, i.e., its contiguous ranges of indices, in ascending order.
+    pub fn ranges(
+        &self,
+    ) -> impl ExactSizeIterator<Item = RangeInclusive<I>> + DoubleEndedIterator + use<'a, I> {
+        self.segments_idx_begin.iter().zip(self.segments_len).map(
+            |(segment_idx_begin, segment_len)| {
+                let segment_idx_end = segment_idx_begin
+                    .advance(segment_len.saturating_sub(1))
+                    .unwrap_or(*segment_idx_begin);
+                *segment_idx_begin..=segment_idx_end
+            },
+        )
+    }
+
+    /// Returns the ranges of indices contained in `self` or `other` (or both), in ascending order.
+    /// Unlike the segments returned by [`Self::ranges`], the ranges can be longer than `usize::MAX`.
+    pub fn union<'b>(
+        &self,
+        other: &NciSet<'b, I>,
+    ) -> impl Iterator<Item = RangeInclusive<I>> + use<'a, 'b, I> {
+        let mut ranges = merge_by_start(self.ranges(), other.ranges()).peekable();
+        core::iter::from_fn(move || {
+            let (start, mut end) = ranges.next()?.into_inner();
+            // Ranges overlapping with or adjacent to the current one are merged into it
+            while let Some(range) = ranges.next_if(|range| {
+                end.next()
+                    .is_none_or(|after_end| *range.start() <= after_end)
+            }) {
+                end = end.max(*range.end());
+            }
+            Some(start..=end)
+        })
+    }
+
+    /// Returns the ranges of indices contained in both `self` and `other`, in ascending order.
+    pub fn intersection<'b>(
+        &self,
+        other: &NciSet<'b, I>,
+    ) -> impl Iterator<Item = RangeInclusive<I>> + use<'a, 'b, I> {
+        let mut ranges = self.ranges().peekable();
+        let mut other_ranges = other.ranges().peekable();
+        core::iter::from_fn(move || {
+            loop {
+                let (range, other_range) = (ranges.peek()?, other_ranges.peek()?);
+                let start = *range.start().max(other_range.start());
+                let end = *range.end().min(other_range.end());
+                // The range that ends first cannot intersect with any further ranges
+                if range.end() <= other_range.end() {
+                    ranges.next();
+                } else {
+                    other_ranges.next();
+                }
+                if start <= end {
+                    return Some(start..=end);
+                }
+            }
+        })
+    }
+
+    /// Returns the ranges of indices contained in `self`, but not in `other`, in ascending order.
+    pub fn difference<'b>(
+        &self,
+        other: &NciSet<'b, I>,
+    ) -> impl Iterator<Item = RangeInclusive<I>> + use<'a, 'b, I> {
+        let mut ranges = self.ranges();
+        let mut other_ranges = other.ranges().peekable();
+        // The part of the current range that has not been returned yet
+        let mut remaining_range = None;
+        core::iter::from_fn(move || {
+            loop {
+                let (start, end) = remaining_range
+                    .take()
+                    .or_else(|| ranges.next())?
+                    .into_inner();
+                while other_ranges
+                    .next_if(|other_range| *other_range.end() < start)
+                    .is_some()
+                {}
+                let Some(other_range) = other_ranges.peek().filter(|other| *other.start() <= end)
+                else {
+                    return Some(start..=end);
+                };
+                let (other_start, other_end) = (*other_range.start(), *other_range.end());
+                if other_end < end {
+                    remaining_range = other_end
+                        .next()
+                        .map(|after_other_end| after_other_end..=end);
+                }
+                if start < other_start {
+                    // Both are within the same segment, so their distance cannot overflow
+                    let before_other_start = start.advance(start.distance(other_start)? - 1)?;
+                    return Some(start..=before_other_start);
+                }
+            }
+        })
+    }
+
+    /// Returns whether `self` and `other` have no indices in common.
+    pub fn is_disjoint(&self, other: &NciSet<'_, I>) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    /// Returns whether all indices in `self` are also contained in `other`.
+    pub fn is_subset(&self, other: &NciSet<'_, I>) -> bool {
+        self.difference(other).next().is_none()
+    }
+}
+
+/// Merges two iterators of ranges sorted by their start into one.
+fn merge_by_start<I: NciIndex>(
+    ranges: impl Iterator<Item = RangeInclusive<I>>,
+    other_ranges: impl Iterator<Item = RangeInclusive<I>>,
+) -> impl Iterator<Item = RangeInclusive<I>> {
+    let mut ranges = ranges.peekable();
+    let mut other_ranges = other_ranges.peekable();
+    core::iter::from_fn(move || match (ranges.peek(), other_ranges.peek()) {
+        (Some(range), Some(other_range)) if other_range.start() < range.start() => {
+            other_ranges.next()
+        }
+        (Some(_), _) => ranges.next(),
+        (None, _) => other_ranges.next(),
+    })
+}
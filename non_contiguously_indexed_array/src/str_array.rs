@@ -0,0 +1,121 @@
+use crate::{NciArray, NciArrayIndexIter, NciIndex, NciMemIdx};
+
+/// A variant of [`NciArray`] with string values, which are concatenated into a single `str`.
+/// Instead of a value, each entry stores the offset of its value within the concatenated values.
+/// This avoids storing a reference per value, e.g., for large tables of names.
+/// For values that are slices of other types, see [`NciMultiArray`](crate::NciMultiArray).
+#[derive(Debug)]
+pub struct NciStrArray<'a, I, O = usize> {
+    /// The user-defined index of the first element of each segment.
+    /// See [`NciArray::segments_idx_begin`].
+    pub segments_idx_begin: &'a [I],
+
+    /// The memory index of the first element of each segment.
+    /// See [`NciArray::segments_mem_idx_begin`].
+    pub segments_mem_idx_begin: &'a [usize],
+
+    /// The byte offset of the value of each element within `values`,
+    /// with the value of an element ending where the value of the next element begins.
+    /// Example: `values_begin[2] == 5 && values_begin[3] == 7` means the value of the third element
+    /// is `&values[5..7]`.
+    pub values_begin: &'a [O],
+
+    /// The concatenated values of all elements.
+    pub values: &'a str,
+}
+
+// Implemented manually, since deriving would require `I` and `O` to implement the respective traits,
+// while only references to them are stored.
+impl<I, O> Clone for NciStrArray<'_, I, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, O> Copy for NciStrArray<'_, I, O> {}
+
+impl<I, O> Default for NciStrArray<'_, I, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, O> NciStrArray<'_, I, O> {
+    pub const fn new() -> Self {
+        Self {
+            segments_idx_begin: &[],
+            segments_mem_idx_begin: &[],
+            values_begin: &[],
+            values: "",
+        }
+    }
+}
+
+impl<I: NciIndex, O: NciMemIdx> core::ops::Index<I> for NciStrArray<'_, I, O> {
+    type Output = str;
+
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<'a, I: NciIndex, O: NciMemIdx> NciStrArray<'a, I, O> {
+    /// Returns the number of entries in the array.
+    pub const fn len(&self) -> usize {
+        self.values_begin.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.values_begin.is_empty()
+    }
+
+    /// Returns the value of each entry, in ascending order of their indices.
+    pub fn values(
+        &self,
+    ) -> impl ExactSizeIterator<Item = &'a str> + DoubleEndedIterator + use<'a, I, O> {
+        let array = *self;
+        (0..self.len()).map(move |mem_idx| array.value_of(mem_idx))
+    }
+
+    pub fn indices(&self) -> NciArrayIndexIter<'a, I> {
+        self.values_begin_array().indices()
+    }
+
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (I, &'a str)> + DoubleEndedIterator + use<'a, I, O> {
+        self.indices().zip(self.values())
+    }
+
+    pub fn has_entry(&self, index: I) -> bool {
+        self.values_begin_array().has_entry(index)
+    }
+
+    pub fn get(&self, index: I) -> Option<&'a str> {
+        self.values_begin_array()
+            .mem_idx(index)
+            .map(|mem_idx| self.value_of(mem_idx))
+    }
+
+    /// Returns the value of the element with the given memory index.
+    /// Panics in case there are fewer than `mem_idx + 1` elements,
+    /// or the offsets of the value are not on `char` boundaries.
+    fn value_of(&self, mem_idx: usize) -> &'a str {
+        let value_end = self
+            .values_begin
+            .get(mem_idx + 1)
+            .map_or(self.values.len(), |next_value_begin| {
+                next_value_begin.to_usize()
+            });
+        &self.values[self.values_begin[mem_idx].to_usize()..value_end]
+    }
+
+    /// Returns an array with the same segment layout, storing the offset of the value of each entry.
+    const fn values_begin_array(&self) -> NciArray<'a, I, O> {
+        NciArray {
+            segments_idx_begin: self.segments_idx_begin,
+            segments_mem_idx_begin: self.segments_mem_idx_begin,
+            values: self.values_begin,
+        }
+    }
+}
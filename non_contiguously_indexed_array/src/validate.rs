@@ -0,0 +1,194 @@
+use crate::{NciArray, NciIndex, NciLayoutError, NciMemIdx};
+
+impl<'a, I: NciIndex, V, M: NciMemIdx> NciArray<'a, I, V, M> {
+    /// Creates an array from its parts, checking that they form a valid layout (see [`NciArray::validate`]).
+    pub fn try_from_parts(
+        segments_idx_begin: &'a [I],
+        segments_mem_idx_begin: &'a [M],
+        values: &'a [V],
+    ) -> Result<Self, NciLayoutError> {
+        let array = Self {
+            segments_idx_begin,
+            segments_mem_idx_begin,
+            values,
+        };
+        array.validate()?;
+        Ok(array)
+    }
+
+    /// Checks that the array has a valid layout, i.e., one that `NciArrayBuilder` could have generated.
+    /// Lookups and iteration on an array with an invalid layout can yield wrong results or panic.
+    pub fn validate(&self) -> Result<(), NciLayoutError> {
+        self.validate_mem_layout()?;
+        for segment in 0..self.segments_idx_begin.len() {
+            let segment_idx_end = self.segments_idx_begin[segment]
+                .advance(self.segment_len(segment) - 1)
+                .ok_or(NciLayoutError::SegmentOverflow { segment })?;
+            if let Some(next_segment_idx_begin) = self.segments_idx_begin.get(segment + 1) {
+                if *next_segment_idx_begin <= segment_idx_end {
+                    return Err(NciLayoutError::UnsortedIdx {
+                        segment: segment + 1,
+                    });
+                }
+                if segment_idx_end.next() == Some(*next_segment_idx_begin) {
+                    return Err(NciLayoutError::AdjacentSegments {
+                        segment: segment + 1,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, V, M: NciMemIdx> NciArray<'_, I, V, M> {
+    /// Checks the part of the layout that does not depend on the index type, i.e., everything except
+    /// for the ordering of the segments. For use in const contexts, see `NciArray::validate_const`.
+    pub fn validate_mem_layout(&self) -> Result<(), NciLayoutError> {
+        let segment_count = self.segments_mem_idx_begin.len();
+        if self.segments_idx_begin.len() != segment_count {
+            return Err(NciLayoutError::SegmentCountMismatch {
+                segments_idx_begin_len: self.segments_idx_begin.len(),
+                segments_mem_idx_begin_len: segment_count,
+            });
+        }
+        let Some(first_mem_idx) = self.segments_mem_idx_begin.first() else {
+            return if self.values.is_empty() {
+                Ok(())
+            } else {
+                Err(NciLayoutError::MissingSegments)
+            };
+        };
+        if first_mem_idx.to_usize() != 0 {
+            return Err(NciLayoutError::FirstMemIdxNotZero {
+                mem_idx: first_mem_idx.to_usize(),
+            });
+        }
+        for segment in 0..segment_count {
+            let mem_idx = self.segments_mem_idx_begin[segment].to_usize();
+            if mem_idx >= self.values.len() {
+                return Err(NciLayoutError::MemIdxOutOfBounds { segment });
+            }
+            if segment > 0 && mem_idx <= self.segments_mem_idx_begin[segment - 1].to_usize() {
+                return Err(NciLayoutError::UnsortedMemIdx { segment });
+            }
+        }
+        Ok(())
+    }
+}
+
+// Trait methods cannot be called in const contexts, so the const version of `validate_mem_layout`
+// is implemented separately for each primitive memory index type.
+macro_rules! impl_validate_mem_layout_const_for_mem_idx {
+    ($m:ty) => {
+        impl<I, V> NciArray<'_, I, V, $m> {
+            /// Same as [`NciArray::validate_mem_layout`], but usable in const contexts.
+            const fn validate_mem_layout_const(&self) -> Result<(), NciLayoutError> {
+                let segment_count = self.segments_mem_idx_begin.len();
+                if self.segments_idx_begin.len() != segment_count {
+                    return Err(NciLayoutError::SegmentCountMismatch {
+                        segments_idx_begin_len: self.segments_idx_begin.len(),
+                        segments_mem_idx_begin_len: segment_count,
+                    });
+                }
+                if segment_count == 0 {
+                    return if self.values.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(NciLayoutError::MissingSegments)
+                    };
+                }
+                if self.segments_mem_idx_begin[0] != 0 {
+                    return Err(NciLayoutError::FirstMemIdxNotZero {
+                        mem_idx: self.segments_mem_idx_begin[0] as usize,
+                    });
+                }
+                let mut segment = 0;
+                while segment < segment_count {
+                    let mem_idx = self.segments_mem_idx_begin[segment];
+                    if mem_idx as usize >= self.values.len() {
+                        return Err(NciLayoutError::MemIdxOutOfBounds { segment });
+                    }
+                    if segment > 0 && mem_idx <= self.segments_mem_idx_begin[segment - 1] {
+                        return Err(NciLayoutError::UnsortedMemIdx { segment });
+                    }
+                    segment += 1;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_validate_mem_layout_const_for_mem_idx!(u8);
+impl_validate_mem_layout_const_for_mem_idx!(u16);
+impl_validate_mem_layout_const_for_mem_idx!(u32);
+impl_validate_mem_layout_const_for_mem_idx!(usize);
+
+macro_rules! impl_validate_const_for_primitive_num {
+    ($t:ty, $u:ty, $checked_add_unsigned:ident) => {
+        impl_validate_const_for_primitive_num!($t, $u, $checked_add_unsigned, u8);
+        impl_validate_const_for_primitive_num!($t, $u, $checked_add_unsigned, u16);
+        impl_validate_const_for_primitive_num!($t, $u, $checked_add_unsigned, u32);
+        impl_validate_const_for_primitive_num!($t, $u, $checked_add_unsigned, usize);
+    };
+    ($t:ty, $u:ty, $checked_add_unsigned:ident, $m:ty) => {
+        impl<V> NciArray<'_, $t, V, $m> {
+            /// Same as [`NciArray::validate`], but usable in const contexts,
+            /// e.g., to check generated arrays at compile time.
+            pub const fn validate_const(&self) -> Result<(), NciLayoutError> {
+                if let Err(error) = self.validate_mem_layout_const() {
+                    return Err(error);
+                }
+                let segment_count = self.segments_idx_begin.len();
+                let mut segment = 0;
+                while segment < segment_count {
+                    let segment_mem_idx_end = if segment + 1 < segment_count {
+                        self.segments_mem_idx_begin[segment + 1] as usize
+                    } else {
+                        self.values.len()
+                    };
+                    let last_distance =
+                        segment_mem_idx_end - self.segments_mem_idx_begin[segment] as usize - 1;
+                    if last_distance as u128 > <$u>::MAX as u128 {
+                        return Err(NciLayoutError::SegmentOverflow { segment });
+                    }
+                    let Some(segment_idx_end) =
+                        self.segments_idx_begin[segment].$checked_add_unsigned(last_distance as $u)
+                    else {
+                        return Err(NciLayoutError::SegmentOverflow { segment });
+                    };
+                    if segment + 1 < segment_count {
+                        let next_segment_idx_begin = self.segments_idx_begin[segment + 1];
+                        if next_segment_idx_begin <= segment_idx_end {
+                            return Err(NciLayoutError::UnsortedIdx {
+                                segment: segment + 1,
+                            });
+                        }
+                        if next_segment_idx_begin - 1 == segment_idx_end {
+                            return Err(NciLayoutError::AdjacentSegments {
+                                segment: segment + 1,
+                            });
+                        }
+                    }
+                    segment += 1;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_validate_const_for_primitive_num!(u8, u8, checked_add);
+impl_validate_const_for_primitive_num!(u16, u16, checked_add);
+impl_validate_const_for_primitive_num!(u32, u32, checked_add);
+impl_validate_const_for_primitive_num!(u64, u64, checked_add);
+impl_validate_const_for_primitive_num!(u128, u128, checked_add);
+impl_validate_const_for_primitive_num!(usize, usize, checked_add);
+
+impl_validate_const_for_primitive_num!(i8, u8, checked_add_unsigned);
+impl_validate_const_for_primitive_num!(i16, u16, checked_add_unsigned);
+impl_validate_const_for_primitive_num!(i32, u32, checked_add_unsigned);
+impl_validate_const_for_primitive_num!(i64, u64, checked_add_unsigned);
+impl_validate_const_for_primitive_num!(i128, u128, checked_add_unsigned);
+impl_validate_const_for_primitive_num!(isize, usize, checked_add_unsigned);
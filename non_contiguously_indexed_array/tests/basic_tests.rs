@@ -1,9 +1,15 @@
-mod constants;
-use constants::*;
+mod common;
+use common::*;
 #[macro_use] // TODO: Import the macros properly, without needing to suppress warnings
 mod macros;
 
-use non_contiguously_indexed_array::NciIndex;
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::num::{
+    NonZeroI8, NonZeroI32, NonZeroIsize, NonZeroU8, NonZeroU32, NonZeroU64, NonZeroU128,
+    NonZeroUsize,
+};
+
+use non_contiguously_indexed_array::{NciArray, NciBoundedIndex, NciIndex};
 
 #[test]
 fn basic_array_test_1() {
@@ -145,6 +151,312 @@ fn basic_index_test_3() {
     );
 }
 
+#[test]
+fn basic_index_test_4() {
+    assert_eq!(0u8.advance(255), Some(u8::MAX));
+    assert_eq!(1u8.advance(255), None);
+    assert_eq!(i8::MIN.advance(255), Some(i8::MAX));
+    assert_eq!(i8::MIN.advance(256), None);
+    assert_eq!((-5i32).advance(10), Some(5));
+    assert_eq!(u128::MAX.advance(0), Some(u128::MAX));
+    assert_eq!(
+        i128::MIN.advance(usize::MAX),
+        i128::MIN.checked_add_unsigned(usize::MAX as u128)
+    );
+}
+
+#[test]
+fn basic_index_test_size() {
+    basic_next_test_normal_case!(0usize, usize::MAX - 1, isize::MIN, -1isize, 0isize);
+    basic_next_test_edge_case!(usize::MAX, isize::MAX);
+
+    basic_distance_test_normal_case!(
+        (0usize, usize::MAX, usize::MAX),
+        (isize::MIN, isize::MAX, usize::MAX),
+        (-1isize, 1, 2)
+    );
+
+    assert_eq!(0usize.advance(usize::MAX), Some(usize::MAX));
+    assert_eq!(1usize.advance(usize::MAX), None);
+    assert_eq!(isize::MIN.advance(usize::MAX), Some(isize::MAX));
+}
+
+#[test]
+fn basic_index_test_non_zero() {
+    basic_next_test_normal_case!(
+        NonZeroU8::MIN,
+        NonZeroU32::new(41).unwrap(),
+        NonZeroU64::new(u64::MAX - 1).unwrap(),
+        NonZeroUsize::MIN
+    );
+    basic_next_test_edge_case!(
+        NonZeroU8::MAX,
+        NonZeroU32::MAX,
+        NonZeroU128::MAX,
+        NonZeroUsize::MAX,
+        NonZeroI8::MAX,
+        NonZeroI32::MAX,
+        NonZeroIsize::MAX
+    );
+
+    assert_eq!(
+        NonZeroU32::MIN.distance(NonZeroU32::MAX),
+        usize::try_from(u32::MAX - 1).ok()
+    );
+    assert_eq!(
+        NonZeroUsize::MIN.distance(NonZeroUsize::MAX),
+        Some(usize::MAX - 1)
+    );
+    basic_distance_test_edge_case!((NonZeroU128::MIN, NonZeroU128::MAX));
+    assert_eq!(NonZeroU8::MIN.advance(254), Some(NonZeroU8::MAX));
+    assert_eq!(NonZeroU8::MIN.advance(255), None);
+
+    // Signed non-zero integers skip 0
+    let minus_one = NonZeroI32::new(-1).unwrap();
+    let one = NonZeroI32::new(1).unwrap();
+    assert_eq!(minus_one.next(), Some(one));
+    assert_eq!(NonZeroI32::MIN.next(), NonZeroI32::new(i32::MIN + 1));
+    assert_eq!(minus_one.distance(one), Some(1));
+    assert_eq!(one.distance(minus_one), Some(1));
+    assert_eq!(one.distance(NonZeroI32::new(5).unwrap()), Some(4));
+    assert_eq!(minus_one.advance(3), NonZeroI32::new(3));
+    assert_eq!(NonZeroI32::new(-5).unwrap().advance(2), NonZeroI32::new(-3));
+    assert_eq!(
+        NonZeroI8::MIN.distance(NonZeroI8::MAX),
+        Some(usize::from(u8::MAX) - 1)
+    );
+    assert_eq!(NonZeroI8::MIN.advance(254), Some(NonZeroI8::MAX));
+    assert_eq!(NonZeroI8::MIN.advance(255), None);
+    assert_eq!(
+        NonZeroIsize::MIN.distance(NonZeroIsize::MAX),
+        Some(usize::MAX - 1)
+    );
+    assert_eq!(NonZeroIsize::MIN.advance(usize::MAX), None);
+}
+
+#[test]
+fn basic_index_test_char() {
+    assert_eq!('a'.next(), Some('b'));
+    assert_eq!('\u{D7FF}'.next(), Some('\u{E000}'));
+    assert_eq!(char::MAX.next(), None);
+
+    assert_eq!('a'.distance('z'), Some(25));
+    assert_eq!('\u{D7FF}'.distance('\u{E000}'), Some(1));
+    assert_eq!('\u{E000}'.distance('\u{D7FF}'), Some(1));
+    assert_eq!('\0'.distance(char::MAX), Some(char::MAX as usize - 0x800));
+
+    assert_eq!('a'.advance(25), Some('z'));
+    assert_eq!('\u{D7FE}'.advance(3), Some('\u{E001}'));
+    assert_eq!('\0'.advance(char::MAX as usize - 0x800), Some(char::MAX));
+    assert_eq!('\0'.advance(char::MAX as usize - 0x800 + 1), None);
+    assert_eq!(char::MAX.advance(usize::MAX), None);
+}
+
+#[test]
+fn basic_array_test_char() {
+    // A single segment spanning the surrogate code points
+    let array: NciArray<char, u32> = NciArray {
+        segments_idx_begin: &['A', '\u{D7FE}'],
+        segments_mem_idx_begin: &[0, 2],
+        values: &[0x41, 0x42, 0xD7FE, 0xD7FF, 0xE000, 0xE001],
+    };
+    assert_eq!(array.validate(), Ok(()));
+    assert!(array.entries().all(|(index, value)| index as u32 == *value));
+    assert_eq!(array.indices().nth(4), Some('\u{E000}'));
+    assert_eq!(array.get('\u{E001}'), Some(&0xE001));
+    assert_eq!(array.get('\u{E002}'), None);
+    assert_eq!(array.range('C'..).count(), 4);
+}
+
+#[test]
+fn basic_index_test_ip() {
+    assert_eq!(
+        Ipv4Addr::new(10, 0, 0, 255).next(),
+        Some(Ipv4Addr::new(10, 0, 1, 0))
+    );
+    assert_eq!(Ipv4Addr::BROADCAST.next(), None);
+    assert_eq!(
+        Ipv4Addr::new(10, 0, 0, 0).distance(Ipv4Addr::new(10, 0, 2, 0)),
+        Some(512)
+    );
+    assert_eq!(
+        Ipv4Addr::UNSPECIFIED.advance(0x0A00_0001),
+        Some(Ipv4Addr::new(10, 0, 0, 1))
+    );
+    assert_eq!(Ipv4Addr::new(255, 255, 255, 0).advance(256), None);
+
+    assert_eq!(
+        Ipv6Addr::LOCALHOST.next(),
+        Some(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 2))
+    );
+    assert_eq!(Ipv6Addr::from_bits(u128::MAX).next(), None);
+    assert_eq!(
+        Ipv6Addr::UNSPECIFIED.distance(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 1, 0)),
+        Some(0x1_0000)
+    );
+    assert_eq!(
+        Ipv6Addr::UNSPECIFIED.distance(Ipv6Addr::from_bits(u128::MAX)),
+        None
+    );
+    assert_eq!(
+        Ipv6Addr::LOCALHOST.advance(usize::MAX),
+        Some(Ipv6Addr::from_bits(u128::from(usize::MAX as u64) + 1))
+    );
+    assert_eq!(Ipv6Addr::from_bits(u128::MAX).advance(1), None);
+}
+
+#[test]
+fn basic_index_test_bytes() {
+    assert_eq!([0u8, 255].next(), Some([1, 0]));
+    assert_eq!([1u8, 255, 255].next(), Some([2, 0, 0]));
+    assert_eq!([255u8; 4].next(), None);
+    assert_eq!([].next(), None::<[u8; 0]>);
+
+    assert_eq!([1u8, 0].distance([0, 255]), Some(1));
+    assert_eq!([0u8, 255].distance([1, 0]), Some(1));
+    assert_eq!([2u8, 1, 0].distance([1, 2, 3]), Some(0xFEFD));
+    assert_eq!([0u8; 32].distance([0; 32]), Some(0));
+    let mut max_distance = [0u8; 32];
+    max_distance[32 - size_of::<usize>()..].fill(255);
+    assert_eq!([0u8; 32].distance(max_distance), Some(usize::MAX));
+    max_distance[31 - size_of::<usize>()] = 1;
+    assert_eq!([0u8; 32].distance(max_distance), None);
+
+    assert_eq!([0u8, 200].advance(100), Some([1, 44]));
+    assert_eq!([0u8, 0, 0].advance(0x01_02_03), Some([1, 2, 3]));
+    assert_eq!([0u8, 255].advance(0xFF00), Some([255, 255]));
+    assert_eq!([0u8, 255].advance(0xFF01), None);
+    assert_eq!([0u8; 32].advance(usize::MAX).unwrap()[31], 255);
+}
+
+#[test]
+fn basic_index_test_tuple() {
+    assert_eq!((1u32, 5u8).next(), Some((1, 6)));
+    assert_eq!((1u32, u8::MAX).next(), Some((2, 0)));
+    assert_eq!((u32::MAX, u8::MAX).next(), None);
+    assert_eq!((-1i8, NonZeroU8::MAX).next(), Some((0, NonZeroU8::MIN)));
+
+    assert_eq!((1u32, 5u8).distance((1, 2)), Some(3));
+    assert_eq!((1u32, 250u8).distance((2, 5)), Some(11));
+    assert_eq!((2u32, 5u8).distance((1, 250)), Some(11));
+    assert_eq!((0u32, 0u8).distance((3, 0)), Some(768));
+    assert_eq!((0u8, u64::MAX).distance((1, 0)), Some(1));
+    assert_eq!((0u8, u64::MAX).distance((1, 1)), Some(2));
+    assert_eq!((0u8, 0u64).distance((1, 0)), None);
+    assert_eq!((0u8, u128::MAX).distance((1, 0)), Some(1));
+
+    assert_eq!((1u32, 5u8).advance(0), Some((1, 5)));
+    assert_eq!((1u32, 5u8).advance(250), Some((1, 255)));
+    assert_eq!((1u32, 5u8).advance(251), Some((2, 0)));
+    assert_eq!((1u32, 5u8).advance(1000), Some((4, 237)));
+    assert_eq!(
+        (0u8, u64::MAX).advance(usize::MAX),
+        Some((1, usize::MAX as u64 - 1))
+    );
+    assert_eq!((u8::MAX, 5u8).advance(251), None);
+
+    assert_eq!((0u8, 1u8, 2u8).next(), Some((0, 1, 3)));
+    assert_eq!((0u8, 1u8, u8::MAX).next(), Some((0, 2, 0)));
+    assert_eq!((0u8, u8::MAX, u8::MAX).next(), Some((1, 0, 0)));
+    assert_eq!((0u8, 0u8, 0u8).distance((1, 0, 0)), Some(0x1_0000));
+    assert_eq!((0u8, 0u8, 0u8).advance(0x1_0203), Some((1, 2, 3)));
+    assert_eq!(
+        (0u8, 0u8, 0u8, 0u8).advance(0x0102_0304),
+        Some((1, 2, 3, 4))
+    );
+    assert_eq!(
+        <(u8, u16, char) as NciBoundedIndex>::MAX,
+        (u8::MAX, u16::MAX, char::MAX)
+    );
+}
+
+#[test]
+fn basic_index_test_tuple_declared_bounds() {
+    /// A column of a table with 10 columns, which delegates to `u16` like a derived `NciIndex`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Column(u16);
+
+    impl NciIndex for Column {
+        fn next(self) -> Option<Self> {
+            self.0.next().map(Self)
+        }
+        fn distance(self, other: Self) -> Option<usize> {
+            self.0.distance(other.0)
+        }
+        fn advance(self, n: usize) -> Option<Self> {
+            self.0.advance(n).map(Self)
+        }
+    }
+
+    impl NciBoundedIndex for Column {
+        const MIN: Self = Self(0);
+        const MAX: Self = Self(9);
+    }
+
+    assert_eq!((1u16, Column(8)).next(), Some((1, Column(9))));
+    assert_eq!((1u16, Column(9)).next(), Some((2, Column(0))));
+    assert_eq!((1u16, Column(5)).distance((3, Column(2))), Some(17));
+    assert_eq!((1u16, Column(5)).advance(17), Some((3, Column(2))));
+    assert_eq!((u16::MAX, Column(9)).next(), None);
+}
+
+#[test]
+fn basic_array_test_tuple() {
+    // Two rows of 3 cells each, and 4 cells wrapping around from one row to the next
+    let array: NciArray<(u16, u8), u32> = NciArray {
+        segments_idx_begin: &[(0, 10), (1, 10), (2, 254)],
+        segments_mem_idx_begin: &[0, 3, 6],
+        values: &[10, 11, 12, 110, 111, 112, 2254, 2255, 3000, 3001],
+    };
+    assert_eq!(array.validate(), Ok(()));
+    assert_eq!(array.get((1, 11)), Some(&111));
+    assert_eq!(array.get((1, 13)), None);
+    assert_eq!(array.get((3, 0)), Some(&3000));
+    assert_eq!(array[(3, 1)], 3001);
+    assert!(
+        array
+            .indices()
+            .skip(6)
+            .eq([(2, 254), (2, 255), (3, 0), (3, 1)])
+    );
+    assert_eq!(array.range((1, 0)..(3, 0)).count(), 5);
+}
+
+#[test]
+fn basic_index_test_rust_expr() {
+    struct RustExpr<I>(I);
+
+    impl<I: NciIndex + std::fmt::Debug> std::fmt::Display for RustExpr<I> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt_rust_expr(f)
+        }
+    }
+
+    assert_eq!(RustExpr(-5i32).to_string(), "-5");
+    assert_eq!(RustExpr('a').to_string(), "'a'");
+    assert_eq!(RustExpr([1u8, 2]).to_string(), "[1, 2]");
+    assert_eq!(
+        RustExpr(NonZeroI8::new(-3).unwrap()).to_string(),
+        "::core::num::NonZeroI8::new(-3).unwrap()"
+    );
+    assert_eq!(
+        RustExpr(Ipv4Addr::new(192, 168, 0, 1)).to_string(),
+        "::core::net::Ipv4Addr::new(192, 168, 0, 1)"
+    );
+    assert_eq!(
+        RustExpr(Ipv6Addr::LOCALHOST).to_string(),
+        "::core::net::Ipv6Addr::from_bits(0x1)"
+    );
+    assert_eq!(
+        RustExpr((NonZeroU8::new(3).unwrap(), 4u8)).to_string(),
+        "(::core::num::NonZeroU8::new(3).unwrap(), 4)"
+    );
+    assert_eq!(
+        RustExpr((1u8, Ipv4Addr::new(10, 0, 0, 1), 'c')).to_string(),
+        "(1, ::core::net::Ipv4Addr::new(10, 0, 0, 1), 'c')"
+    );
+}
+
 macro_rules! basic_iterator_test {
     ($a:tt) => {
         let mut entries = $a.entries();
@@ -0,0 +1,163 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::{NciBitmapArray, NciIndex};
+
+/// `ARRAY_1` as a bitmap.
+const BITMAP_ARRAY_1: NciBitmapArray<u32, u32> = NciBitmapArray {
+    idx_begin: Some(0),
+    bitmap: &[0b1100_0000_0111, 1 << (100 - 64)],
+    rank_samples: &[0],
+    values: &[0, 1, 2, 10, 11, 100],
+};
+
+/// `ARRAY_4` as a bitmap.
+const BITMAP_ARRAY_4: NciBitmapArray<i32, i32> = NciBitmapArray {
+    idx_begin: Some(-500),
+    bitmap: &[
+        0b11,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        // -500 + 7 * 64 = -52, so -2 to 2 are bits 50 to 54
+        0b11111 << 50,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        // -500 + 15 * 64 = 460, so 499 and 500 are bits 39 and 40
+        0b11 << 39,
+    ],
+    rank_samples: &[0, 7],
+    values: &[-500, -499, -2, -1, 0, 1, 2, 499, 500],
+};
+
+/// Creates a bitmap array with an entry for every index in `range` that satisfies `has_entry`,
+/// with the value being the index.
+fn bitmap_array_from_predicate(
+    range: core::ops::Range<i32>,
+    has_entry: impl Fn(i32) -> bool,
+) -> (Vec<u64>, Vec<usize>, Vec<i32>) {
+    let mut bitmap = vec![0u64; range.len().div_ceil(64)];
+    let mut values = Vec::new();
+    for (distance, index) in range.enumerate() {
+        if has_entry(index) {
+            bitmap[distance / 64] |= 1 << (distance % 64);
+            values.push(index);
+        }
+    }
+    let rank_samples = (0..bitmap.len())
+        .step_by(NciBitmapArray::<i32, i32>::WORDS_PER_RANK_SAMPLE)
+        .map(|word| {
+            bitmap[..word]
+                .iter()
+                .map(|bits| bits.count_ones() as usize)
+                .sum()
+        })
+        .collect();
+    (bitmap, rank_samples, values)
+}
+
+fn assert_same_entries<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    bitmap_array: NciBitmapArray<I, V>,
+    expected: &[(I, &V)],
+    points: &[I],
+) {
+    assert_eq!(bitmap_array.len(), expected.len());
+    assert!(bitmap_array.entries().eq(expected.iter().copied()));
+    assert!(
+        bitmap_array
+            .entries()
+            .rev()
+            .eq(expected.iter().rev().copied())
+    );
+    assert!(
+        bitmap_array
+            .indices()
+            .eq(expected.iter().map(|(index, _)| *index))
+    );
+    for (index, value) in expected {
+        assert_eq!(bitmap_array.get(*index), Some(*value));
+        assert_eq!(bitmap_array[*index], **value);
+        assert!(bitmap_array.has_entry(*index));
+    }
+    for point in points {
+        let expected_value = expected_value(expected, *point).copied();
+        assert_eq!(bitmap_array.get(*point), expected_value, "{point:?}");
+        assert_eq!(bitmap_array.has_entry(*point), expected_value.is_some());
+    }
+}
+
+#[test]
+fn bitmap_array_test_1() {
+    assert_same_entries(
+        BITMAP_ARRAY_1,
+        &ARRAY_1.entries().collect::<Vec<_>>(),
+        &[3, 5, 9, 12, 63, 64, 99, 101, 127, 128, 500, u32::MAX],
+    );
+}
+
+#[test]
+fn bitmap_array_test_4() {
+    assert_same_entries(
+        BITMAP_ARRAY_4,
+        &ARRAY_4.entries().collect::<Vec<_>>(),
+        &[i32::MIN, -501, -498, -3, 3, 498, 501, 524, i32::MAX],
+    );
+}
+
+#[test]
+fn bitmap_array_test_holey() {
+    let (bitmap, rank_samples, values) =
+        bitmap_array_from_predicate(-1000..1000, |index| index.rem_euclid(10) != 3);
+    let bitmap_array = NciBitmapArray {
+        idx_begin: Some(-1000),
+        bitmap: &bitmap,
+        rank_samples: &rank_samples,
+        values: &values,
+    };
+    assert_eq!(bitmap_array.len(), 1800);
+    for index in -1100..1100i32 {
+        let expected =
+            ((-1000..1000).contains(&index) && index.rem_euclid(10) != 3).then_some(&index);
+        assert_eq!(bitmap_array.get(index), expected, "{index}");
+    }
+    assert!(bitmap_array.values().eq(values.iter()));
+}
+
+#[test]
+fn bitmap_array_test_iter_both_ends() {
+    let mut entries = BITMAP_ARRAY_4.entries();
+    assert_eq!(entries.next(), Some((-500, &-500)));
+    assert_eq!(entries.next_back(), Some((500, &500)));
+    assert_eq!(entries.next_back(), Some((499, &499)));
+    assert_eq!(entries.len(), 6);
+    assert!(entries.eq(ARRAY_4.range(-499..=2)));
+
+    // Both ends within the same word
+    let mut indices = BITMAP_ARRAY_1.indices();
+    assert_eq!(indices.next_back(), Some(100));
+    assert_eq!(indices.next(), Some(0));
+    assert_eq!(indices.next_back(), Some(11));
+    assert_eq!(indices.next(), Some(1));
+    assert_eq!(indices.next_back(), Some(10));
+    assert_eq!(indices.next(), Some(2));
+    assert_eq!(indices.next(), None);
+    assert_eq!(indices.next_back(), None);
+}
+
+#[test]
+fn bitmap_array_test_empty() {
+    let array = NciBitmapArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.indices().next(), None);
+    assert_eq!(array.entries().next_back(), None);
+}
@@ -0,0 +1,137 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciBucketArray;
+
+#[test]
+fn bucket_array_test_1() {
+    let points = [3, 5, 9, 12, 63, 64, 65, 99, 101, 128, 500, u32::MAX];
+    // Two buckets of 64 indices, as generated by the builder
+    let bucket_array = NciBucketArray {
+        array: ARRAY_1,
+        bucket_shift: 6,
+        bucket_segments: &[0, 1],
+    };
+    assert_same_lookups!(bucket_array, ARRAY_1, &points);
+
+    // One bucket per index up to the last segment
+    let bucket_segments: Vec<_> = (0..=100)
+        .map(|index| {
+            ARRAY_1
+                .segments()
+                .filter(|segment| segment.start <= index)
+                .count()
+                - 1
+        })
+        .collect();
+    let bucket_array = NciBucketArray {
+        array: ARRAY_1,
+        bucket_shift: 0,
+        bucket_segments: &bucket_segments,
+    };
+    assert_same_lookups!(bucket_array, ARRAY_1, &points);
+
+    // A single bucket covering all indices
+    let bucket_array = NciBucketArray {
+        array: ARRAY_1,
+        bucket_shift: u32::MAX,
+        bucket_segments: &[0],
+    };
+    assert_same_lookups!(bucket_array, ARRAY_1, &points);
+}
+
+#[test]
+fn bucket_array_test_2() {
+    let bucket_array = NciBucketArray {
+        array: ARRAY_2,
+        bucket_shift: 7,
+        bucket_segments: &[0, 1, 1, 1],
+    };
+    assert_same_lookups!(
+        bucket_array,
+        ARRAY_2,
+        &[0, 99, 102, 199, 255, 499, 503, 1000],
+    );
+}
+
+#[test]
+fn bucket_array_test_3() {
+    let bucket_array = NciBucketArray {
+        array: ARRAY_3,
+        bucket_shift: 6,
+        bucket_segments: &[0, 1],
+    };
+    assert_same_lookups!(
+        bucket_array,
+        ARRAY_3,
+        &[i32::MIN, -501, -497, -491, -488, -436, -399, 0],
+    );
+}
+
+#[test]
+fn bucket_array_test_4() {
+    let bucket_array = NciBucketArray {
+        array: ARRAY_4,
+        bucket_shift: 9,
+        bucket_segments: &[0, 1],
+    };
+    assert_same_lookups!(
+        bucket_array,
+        ARRAY_4,
+        &[
+            i32::MIN,
+            -510,
+            -501,
+            -498,
+            -250,
+            -3,
+            3,
+            12,
+            250,
+            498,
+            501,
+            i32::MAX,
+        ],
+    );
+}
+
+#[test]
+fn bucket_array_test_5() {
+    // The distances to the later segments exceed `usize::MAX`, so lookups of their indices use the last bucket
+    let bucket_array = NciBucketArray {
+        array: ARRAY_5,
+        bucket_shift: usize::BITS - 1,
+        bucket_segments: &[0, 0],
+    };
+    assert_same_lookups!(
+        bucket_array,
+        ARRAY_5,
+        &[
+            2,
+            1 << 64,
+            u128::MAX / 2 - 1,
+            u128::MAX / 2 + 2,
+            u128::MAX - 2,
+        ],
+    );
+}
+
+#[test]
+fn bucket_array_test_deref() {
+    let bucket_array = NciBucketArray {
+        array: ARRAY_1,
+        bucket_shift: 6,
+        bucket_segments: &[0, 1],
+    };
+    assert!(bucket_array.entries().eq(ARRAY_1.entries()));
+    assert!(bucket_array.range(5..=100).eq(ARRAY_1.range(5..=100)));
+    assert_eq!(bucket_array.floor(50), Some((11, &11)));
+}
+
+#[test]
+fn bucket_array_test_empty() {
+    let array = NciBucketArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+}
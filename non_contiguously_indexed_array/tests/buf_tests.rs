@@ -0,0 +1,172 @@
+mod common;
+use common::*;
+#[macro_use] // TODO: Import the macros properly, without needing to suppress warnings
+mod macros;
+
+use non_contiguously_indexed_array::{NciArray, NciArrayBuf, NciIndex};
+
+fn assert_conversion_roundtrip<
+    I: NciIndex + std::fmt::Debug,
+    V: Clone + PartialEq + std::fmt::Debug,
+>(
+    array: NciArray<I, V>,
+) {
+    let owned = NciArrayBuf::from(array);
+    let view = NciArray::from(&owned);
+    assert!(view.entries().eq(array.entries()));
+    assert!(owned.as_array().entries().eq(array.entries()));
+    assert!(owned.as_array().values().eq(array.values()));
+}
+
+#[test]
+fn buf_from_entries_test_1() {
+    let array = NciArrayBuf::from_entries([(100, 100), (0, 0), (11, 11), (2, 2), (1, 1), (10, 10)]);
+    let view = array.as_array();
+    basic_array_test_normal_case!(view, 0, 1, 2, 10, 11, 100);
+    basic_array_test_edge_case!(view, 3, 5, 9, 55, 99, 101, 500);
+
+    assert_eq!(view.segments_idx_begin, ARRAY_1.segments_idx_begin);
+    assert_eq!(view.segments_mem_idx_begin, ARRAY_1.segments_mem_idx_begin);
+    assert_eq!(view.values, ARRAY_1.values);
+}
+
+#[test]
+fn buf_from_entries_test_2() {
+    let array: NciArrayBuf<i32, &str> = [(5, "a"), (-1, "b"), (5, "c"), (6, "d")]
+        .into_iter()
+        .collect();
+    assert_eq!(array.as_array().len(), 3);
+    assert_eq!(array[5], "c");
+    assert_eq!(
+        array.as_array().entries().collect::<Vec<_>>(),
+        [(-1, &"b"), (5, &"c"), (6, &"d")]
+    );
+
+    let empty = NciArrayBuf::<i32, &str>::from_entries([]);
+    assert!(empty.as_array().is_empty());
+    assert_eq!(empty.as_array().get(0), None);
+    assert_eq!(empty.as_array().indices().next(), None);
+}
+
+#[test]
+fn buf_conversion_test() {
+    assert_conversion_roundtrip(ARRAY_1);
+    assert_conversion_roundtrip(ARRAY_2);
+    assert_conversion_roundtrip(ARRAY_3);
+    assert_conversion_roundtrip(ARRAY_4);
+    assert_conversion_roundtrip(ARRAY_5);
+}
+
+fn assert_layout<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    array: &NciArrayBuf<I, V>,
+    segments_idx_begin: &[I],
+    segments_mem_idx_begin: &[usize],
+) {
+    let view = array.as_array();
+    assert_eq!(view.segments_idx_begin, segments_idx_begin);
+    assert_eq!(view.segments_mem_idx_begin, segments_mem_idx_begin);
+}
+
+#[test]
+fn buf_insert_test() {
+    let mut array = NciArrayBuf::new();
+    assert_eq!(array.insert(10u32, 10u32), None);
+    assert_eq!(array.insert(0, 0), None);
+    assert_layout(&array, &[0, 10], &[0, 1]);
+
+    // Extend at the end of a segment
+    assert_eq!(array.insert(1, 1), None);
+    assert_layout(&array, &[0, 10], &[0, 2]);
+
+    // Extend at the beginning of a segment
+    assert_eq!(array.insert(9, 9), None);
+    assert_layout(&array, &[0, 9], &[0, 2]);
+
+    // Bridge the gap between two segments
+    for i in 2..9 {
+        assert_eq!(array.insert(i, i), None);
+    }
+    assert_layout(&array, &[0], &[0]);
+
+    // Replace an existing value
+    assert_eq!(array.insert(5, 50), Some(5));
+    assert_eq!(array[5], 50);
+    assert_eq!(array.as_array().len(), 11);
+
+    assert_eq!(array.insert(u32::MAX, u32::MAX), None);
+    assert_layout(&array, &[0, u32::MAX], &[0, 11]);
+}
+
+#[test]
+fn buf_remove_test() {
+    let mut array = NciArrayBuf::from(ARRAY_1);
+    assert_eq!(array.remove(5), None);
+    assert_eq!(array.remove(101), None);
+
+    // Split a segment
+    assert_eq!(array.remove(1), Some(1));
+    assert_layout(&array, &[0, 2, 10, 100], &[0, 1, 2, 4]);
+
+    // Shrink a segment at its beginning and end
+    assert_eq!(array.remove(10), Some(10));
+    assert_layout(&array, &[0, 2, 11, 100], &[0, 1, 2, 3]);
+    assert_eq!(array.remove(2), Some(2));
+    assert_layout(&array, &[0, 11, 100], &[0, 1, 2]);
+
+    // Remove whole segments
+    assert_eq!(array.remove(0), Some(0));
+    assert_eq!(array.remove(100), Some(100));
+    assert_layout(&array, &[11], &[0]);
+    assert_eq!(array.remove(11), Some(11));
+    assert!(array.as_array().is_empty());
+    assert_layout(&array, &[], &[]);
+}
+
+#[test]
+fn buf_insert_remove_consistency_test() {
+    let mut array = NciArrayBuf::new();
+    let mut reference = std::collections::BTreeMap::new();
+    let mut state = 0x2545_f491u32;
+    for _ in 0..2000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let index = (state % 64) as i8 - 32;
+        if state & (1 << 20) == 0 {
+            assert_eq!(array.remove(index), reference.remove(&index));
+        } else {
+            assert_eq!(array.insert(index, state), reference.insert(index, state));
+        }
+
+        let rebuilt = NciArrayBuf::from_entries(reference.iter().map(|(&i, &v)| (i, v)));
+        assert_layout(
+            &array,
+            rebuilt.as_array().segments_idx_begin,
+            rebuilt.as_array().segments_mem_idx_begin,
+        );
+        assert!(
+            array
+                .as_array()
+                .entries()
+                .eq(reference.iter().map(|(&i, v)| (i, v)))
+        );
+    }
+}
+
+#[test]
+fn buf_mutation_test() {
+    let mut array = NciArrayBuf::from(ARRAY_2);
+    array[101] = 0;
+    *array.get_mut(500).unwrap() += 1;
+    assert_eq!(array.get_mut(102), None);
+    for (index, value) in array.as_array_mut().entries_mut() {
+        *value += index;
+    }
+    for value in array.as_array_mut().values_mut() {
+        *value -= 1;
+    }
+    assert_eq!(
+        array.as_array().values().copied().collect::<Vec<_>>(),
+        [199, 100, 399, 1000, 1001, 1003]
+    );
+}
@@ -0,0 +1,37 @@
+//! Constants and oracles shared by the test files.
+#![allow(dead_code, unused_imports, unused_macros)] // Not every constant or oracle is used in every test file
+
+mod constants;
+pub use constants::*;
+
+/// Asserts that an array variant has the same entries as the given `NciArray`,
+/// and returns the same results for lookups of all its indices and of the given other points.
+macro_rules! assert_same_lookups {
+    ($variant:expr, $array:expr, $points:expr $(,)?) => {{
+        let (variant, array) = ($variant, $array);
+        assert_eq!(variant.len(), array.len());
+        assert!(variant.indices().eq(array.indices()));
+        assert!(variant.indices().rev().eq(array.indices().rev()));
+        assert_eq!(variant.indices().len(), array.len());
+        assert!(variant.entries().eq(array.entries()));
+        assert!(variant.entries().rev().eq(array.entries().rev()));
+        for (index, value) in array.entries() {
+            assert_eq!(variant.get(index), Some(value));
+            assert_eq!(variant[index], *value);
+            assert!(variant.has_entry(index));
+        }
+        for point in $points {
+            assert_eq!(variant.get(*point), array.get(*point), "{point:?}");
+            assert_eq!(variant.has_entry(*point), array.has_entry(*point));
+        }
+    }};
+}
+pub(crate) use assert_same_lookups;
+
+/// Returns the value of the given index within the expected entries, if there is one.
+pub fn expected_value<I: PartialEq, V>(expected: &[(I, V)], index: I) -> Option<&V> {
+    expected
+        .iter()
+        .find(|(expected_index, _)| *expected_index == index)
+        .map(|(_, value)| value)
+}
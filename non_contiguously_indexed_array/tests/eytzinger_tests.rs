@@ -0,0 +1,80 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciEytzingerArray;
+
+/// `ARRAY_1` with its segments in Eytzinger order.
+const EYTZINGER_ARRAY_1: NciEytzingerArray<u32, u32> = NciEytzingerArray {
+    segments_idx_begin: &[10, 0, 100],
+    segments_mem_idx_begin: &[3, 0, 5],
+    values: &[0, 1, 2, 10, 11, 100],
+};
+
+/// `ARRAY_4` with its segments in Eytzinger order.
+const EYTZINGER_ARRAY_4: NciEytzingerArray<i32, i32> = NciEytzingerArray {
+    segments_idx_begin: &[-2, -500, 499],
+    segments_mem_idx_begin: &[2, 0, 7],
+    values: &[-500, -499, -2, -1, 0, 1, 2, 499, 500],
+};
+
+/// Six segments, so that the tree is not complete.
+const EYTZINGER_ARRAY_6: NciEytzingerArray<u8, u8> = NciEytzingerArray {
+    segments_idx_begin: &[30, 10, 50, 0, 20, 40],
+    segments_mem_idx_begin: &[6, 2, 10, 0, 4, 8],
+    values: &[0, 1, 10, 11, 20, 21, 30, 31, 40, 41, 50, 51],
+};
+
+#[test]
+fn eytzinger_array_test_1() {
+    assert_same_lookups!(
+        EYTZINGER_ARRAY_1,
+        ARRAY_1,
+        &[3, 5, 9, 12, 55, 99, 101, 500, u32::MAX],
+    );
+}
+
+#[test]
+fn eytzinger_array_test_4() {
+    assert_same_lookups!(
+        EYTZINGER_ARRAY_4,
+        ARRAY_4,
+        &[i32::MIN, -510, -501, -498, -250, -3, 3, 250, 498, 501, 999],
+    );
+}
+
+#[test]
+fn eytzinger_array_test_6() {
+    for index in 0..=u8::MAX {
+        let expected = (index <= 51 && index % 10 < 2).then_some(&index);
+        assert_eq!(EYTZINGER_ARRAY_6.get(index), expected);
+    }
+    assert!(EYTZINGER_ARRAY_6.values().eq(EYTZINGER_ARRAY_6.values));
+    assert!(
+        EYTZINGER_ARRAY_6
+            .entries()
+            .all(|(index, value)| index == *value)
+    );
+
+    // Alternating between the front and the back, which meet between the segments beginning at 20 and 30
+    let mut indices = EYTZINGER_ARRAY_6.indices();
+    assert_eq!(indices.len(), 12);
+    for (front, back) in [(0, 51), (1, 50), (10, 41), (11, 40), (20, 31)] {
+        assert_eq!(indices.next(), Some(front));
+        assert_eq!(indices.next_back(), Some(back));
+    }
+    assert_eq!(indices.next_back(), Some(30));
+    assert_eq!(indices.next(), Some(21));
+    assert_eq!(indices.len(), 0);
+    assert_eq!(indices.next(), None);
+    assert_eq!(indices.next_back(), None);
+}
+
+#[test]
+fn eytzinger_array_test_empty() {
+    let array = NciEytzingerArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.indices().next(), None);
+    assert_eq!(array.entries().next_back(), None);
+}
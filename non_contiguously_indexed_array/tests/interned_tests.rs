@@ -0,0 +1,80 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciInternedArray;
+
+/// The segment layout of `ARRAY_1`, with repeating values.
+const INTERNED_ARRAY_1: NciInternedArray<u32, &str, u8> = NciInternedArray {
+    segments_idx_begin: &[0, 10, 100],
+    segments_mem_idx_begin: &[0, 3, 5],
+    ids: &[0, 1, 0, 2, 1, 0],
+    unique_values: &["zero", "one", "ten"],
+};
+
+/// The segment layout of `ARRAY_4`, with the sign of each index as its value.
+const INTERNED_ARRAY_4: NciInternedArray<i32, i32> = NciInternedArray {
+    segments_idx_begin: &[-500, -2, 499],
+    segments_mem_idx_begin: &[0, 2, 7],
+    ids: &[0, 0, 0, 0, 1, 2, 2, 2, 2],
+    unique_values: &[-1, 0, 1],
+};
+
+#[test]
+fn interned_array_test_1() {
+    let expected = [
+        (0, "zero"),
+        (1, "one"),
+        (2, "zero"),
+        (10, "ten"),
+        (11, "one"),
+        (100, "zero"),
+    ];
+    assert_eq!(INTERNED_ARRAY_1.len(), 6);
+    assert!(
+        INTERNED_ARRAY_1
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(expected)
+    );
+    assert!(
+        INTERNED_ARRAY_1
+            .entries()
+            .rev()
+            .map(|(index, value)| (index, *value))
+            .eq(expected.into_iter().rev())
+    );
+    assert!(ARRAY_1.indices().eq(INTERNED_ARRAY_1.indices()));
+    for index in 0..=200 {
+        let expected_value = expected_value(&expected, index);
+        assert_eq!(INTERNED_ARRAY_1.get(index), expected_value, "{index}");
+        assert_eq!(INTERNED_ARRAY_1.has_entry(index), ARRAY_1.has_entry(index));
+    }
+    assert_eq!(INTERNED_ARRAY_1[11], "one");
+}
+
+#[test]
+fn interned_array_test_4() {
+    assert_eq!(INTERNED_ARRAY_4.len(), ARRAY_4.len());
+    assert!(
+        INTERNED_ARRAY_4
+            .values()
+            .copied()
+            .eq(ARRAY_4.values().map(|value| value.signum()))
+    );
+    for (index, value) in ARRAY_4.entries() {
+        assert_eq!(INTERNED_ARRAY_4.get(index), Some(&value.signum()));
+    }
+    for index in [i32::MIN, -501, -3, 3, 498, 501, i32::MAX] {
+        assert_eq!(INTERNED_ARRAY_4.get(index), None);
+        assert!(!INTERNED_ARRAY_4.has_entry(index));
+    }
+}
+
+#[test]
+fn interned_array_test_empty() {
+    let array = NciInternedArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.entries().next(), None);
+}
@@ -0,0 +1,150 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::{NciArray, NciIndex};
+
+fn assert_iterators_behave<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    array: NciArray<I, V>,
+) {
+    let entries: Vec<_> = array.entries().collect();
+    let indices: Vec<_> = entries.iter().map(|(index, _)| *index).collect();
+
+    assert!(array.indices().rev().eq(indices.iter().copied().rev()));
+    assert!(array.entries().rev().eq(entries.iter().copied().rev()));
+
+    for n in 0..=entries.len() {
+        assert_eq!(array.indices().nth(n), indices.get(n).copied());
+        assert_eq!(array.entries().nth(n), entries.get(n).copied());
+        assert_eq!(
+            array.indices().nth_back(n),
+            indices.iter().rev().nth(n).copied()
+        );
+        assert_eq!(
+            array.entries().nth_back(n),
+            entries.iter().rev().nth(n).copied()
+        );
+
+        // Skip from both ends, then check what remains
+        let mut iter = array.entries();
+        let front = iter.nth(n / 2);
+        let back = iter.nth_back(n - n / 2);
+        let mut remaining = entries.clone();
+        assert_eq!(front, (n / 2 < remaining.len()).then(|| remaining[n / 2]));
+        remaining.drain(..(n / 2 + 1).min(remaining.len()));
+        let back_pos = remaining.len().checked_sub(n - n / 2 + 1);
+        assert_eq!(back, back_pos.map(|pos| remaining[pos]));
+        remaining.truncate(back_pos.unwrap_or(0));
+        assert_eq!(iter.len(), remaining.len());
+        assert_eq!(iter.clone().collect::<Vec<_>>(), remaining);
+        assert!(iter.rev().eq(remaining.into_iter().rev()));
+    }
+
+    let mut iter = array.indices();
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.nth(1), None);
+}
+
+fn assert_seek_behaves<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    array: NciArray<I, V>,
+    points: &[I],
+) {
+    let entries: Vec<_> = array.entries().collect();
+    for &point in points {
+        let expected: Vec<_> = entries
+            .iter()
+            .copied()
+            .filter(|(index, _)| *index >= point)
+            .collect();
+        let mut iter = array.entries();
+        iter.seek(point);
+        assert_eq!(iter.len(), expected.len(), "{point:?}");
+        assert_eq!(iter.clone().collect::<Vec<_>>(), expected, "{point:?}");
+
+        // Seeking backwards does nothing
+        if let Some(first) = entries.first() {
+            iter.seek(first.0);
+            assert_eq!(iter.collect::<Vec<_>>(), expected, "{point:?}");
+        }
+
+        // Seeking respects entries already consumed from the back
+        let mut iter = array.indices();
+        iter.next_back();
+        iter.seek(point);
+        let expected_len = expected.len().saturating_sub(1);
+        assert!(
+            iter.eq(expected.iter().take(expected_len).map(|(index, _)| *index)),
+            "{point:?}"
+        );
+
+        for &second_point in points {
+            let mut iter = array.range(point..);
+            iter.seek(second_point);
+            assert!(
+                iter.eq(entries
+                    .iter()
+                    .copied()
+                    .filter(|(index, _)| *index >= point && *index >= second_point)),
+                "{point:?} {second_point:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn iterator_test_1() {
+    assert_iterators_behave(ARRAY_1);
+    assert_seek_behaves(
+        ARRAY_1,
+        &[0, 1, 2, 3, 9, 10, 11, 12, 99, 100, 101, u32::MAX],
+    );
+}
+
+#[test]
+fn iterator_test_2() {
+    assert_iterators_behave(ARRAY_2);
+    assert_seek_behaves(ARRAY_2, &[0, 100, 101, 102, 199, 200, 201, 500, 502, 503]);
+}
+
+#[test]
+fn iterator_test_3() {
+    assert_iterators_behave(ARRAY_3);
+    assert_seek_behaves(
+        ARRAY_3,
+        &[i32::MIN, -500, -498, -497, -490, -489, -401, -400, 0],
+    );
+}
+
+#[test]
+fn iterator_test_4() {
+    assert_iterators_behave(ARRAY_4);
+    assert_seek_behaves(
+        ARRAY_4,
+        &[-501, -500, -499, -3, -2, 0, 2, 3, 498, 499, 500, 501],
+    );
+}
+
+#[test]
+fn iterator_test_5() {
+    assert_iterators_behave(ARRAY_5);
+    assert_seek_behaves(
+        ARRAY_5,
+        &[
+            0,
+            1,
+            2,
+            u128::MAX / 2,
+            u128::MAX / 2 + 2,
+            u128::MAX - 1,
+            u128::MAX,
+        ],
+    );
+}
+
+#[test]
+fn iterator_test_empty_array() {
+    let array = NciArray::<u8, u8>::new();
+    assert_iterators_behave(array);
+    assert_seek_behaves(array, &[0, 1, u8::MAX]);
+}
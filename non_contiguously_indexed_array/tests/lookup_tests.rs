@@ -0,0 +1,142 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::{NciArray, NciIndex};
+
+fn assert_neighbor_lookups<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    array: NciArray<I, V>,
+    points: &[I],
+) {
+    let entries: Vec<_> = array.entries().collect();
+    for &point in points {
+        let floor = entries.iter().rev().find(|(index, _)| *index <= point);
+        let ceil = entries.iter().find(|(index, _)| *index >= point);
+        let predecessor = entries.iter().rev().find(|(index, _)| *index < point);
+        let successor = entries.iter().find(|(index, _)| *index > point);
+        assert_eq!(array.floor(point), floor.copied(), "{point:?}");
+        assert_eq!(array.ceil(point), ceil.copied(), "{point:?}");
+        assert_eq!(array.predecessor(point), predecessor.copied(), "{point:?}");
+        assert_eq!(array.successor(point), successor.copied(), "{point:?}");
+    }
+}
+
+#[test]
+fn lookup_test_1() {
+    assert_neighbor_lookups(
+        ARRAY_1,
+        &[0, 1, 2, 3, 5, 9, 10, 11, 12, 99, 100, 101, u32::MAX],
+    );
+    assert_eq!(ARRAY_1.floor(50), Some((11, &11)));
+    assert_eq!(ARRAY_1.ceil(50), Some((100, &100)));
+    assert_eq!(ARRAY_1.predecessor(10), Some((2, &2)));
+    assert_eq!(ARRAY_1.successor(2), Some((10, &10)));
+}
+
+#[test]
+fn lookup_test_2() {
+    assert_neighbor_lookups(
+        ARRAY_2,
+        &[0, 99, 100, 101, 102, 200, 201, 499, 500, 502, 503],
+    );
+}
+
+#[test]
+fn lookup_test_3() {
+    assert_neighbor_lookups(
+        ARRAY_3,
+        &[
+            i32::MIN,
+            -501,
+            -500,
+            -499,
+            -498,
+            -497,
+            -491,
+            -490,
+            -400,
+            0,
+            i32::MAX,
+        ],
+    );
+}
+
+#[test]
+fn lookup_test_4() {
+    assert_neighbor_lookups(
+        ARRAY_4,
+        &[-501, -500, -499, -3, -2, 0, 2, 3, 498, 499, 500, 501],
+    );
+}
+
+#[test]
+fn lookup_test_5() {
+    assert_neighbor_lookups(
+        ARRAY_5,
+        &[
+            0,
+            1,
+            2,
+            u128::MAX / 2 - 1,
+            u128::MAX / 2,
+            u128::MAX / 2 + 2,
+            u128::MAX - 2,
+            u128::MAX - 1,
+            u128::MAX,
+        ],
+    );
+}
+
+#[test]
+fn lookup_test_empty_array() {
+    assert_neighbor_lookups(NciArray::<u8, u8>::new(), &[0, 1, u8::MAX]);
+}
+
+fn assert_many_sorted_lookups<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    array: NciArray<I, V>,
+    points: &[I],
+) {
+    let expected: Vec<_> = points.iter().map(|point| array.get(*point)).collect();
+    assert_eq!(
+        array
+            .get_many_sorted(points.iter().copied())
+            .collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn lookup_test_many_sorted() {
+    assert_many_sorted_lookups(
+        ARRAY_1,
+        &[0, 0, 1, 2, 3, 5, 9, 10, 11, 12, 99, 100, 101, u32::MAX],
+    );
+    assert_many_sorted_lookups(ARRAY_2, &[0, 100, 101, 102, 199, 200, 500, 502, 503]);
+    assert_many_sorted_lookups(ARRAY_3, &[-501, -500, -498, -490, -401, -400, 0]);
+    assert_many_sorted_lookups(ARRAY_4, &[-500, -3, -2, 2, 3, 499, 500, 501]);
+    assert_many_sorted_lookups(ARRAY_5, &[0, 2, u128::MAX / 2 + 1, u128::MAX]);
+    assert_many_sorted_lookups(NciArray::<u8, u8>::new(), &[0, 1, u8::MAX]);
+
+    // Many segments of 2 entries each, with the points skipping varying numbers of segments
+    let segments_idx_begin: Vec<u32> = (0..1000).map(|segment| segment * 5).collect();
+    let segments_mem_idx_begin: Vec<usize> = (0..1000).map(|segment| segment * 2).collect();
+    let values: Vec<u32> = segments_idx_begin
+        .iter()
+        .flat_map(|segment_idx_begin| [*segment_idx_begin, segment_idx_begin + 1])
+        .collect();
+    let array = NciArray {
+        segments_idx_begin: &segments_idx_begin,
+        segments_mem_idx_begin: &segments_mem_idx_begin,
+        values: &values,
+    };
+    let points: Vec<u32> = (0..100)
+        .scan(0, |point, step| {
+            *point += step * step;
+            Some(*point)
+        })
+        .collect();
+    assert_many_sorted_lookups(array, &points);
+    assert_many_sorted_lookups(array, &(0..5100).collect::<Vec<_>>());
+
+    // Unsorted indices yield unspecified results, but must not panic
+    assert_eq!(ARRAY_1.get_many_sorted([100, 0, 11, 1]).count(), 4);
+}
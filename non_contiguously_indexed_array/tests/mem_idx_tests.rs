@@ -0,0 +1,79 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::{NciArray, NciArrayMut, NciEytzingerArray};
+
+/// `ARRAY_1` with `u8` memory indices.
+const ARRAY_1_U8: NciArray<u32, u32, u8> = NciArray {
+    segments_idx_begin: &[0, 10, 100],
+    segments_mem_idx_begin: &[0, 3, 5],
+    values: &[0, 1, 2, 10, 11, 100],
+};
+
+/// `ARRAY_4` with `u16` memory indices.
+const ARRAY_4_U16: NciArray<i32, i32, u16> = NciArray {
+    segments_idx_begin: &[-500, -2, 499],
+    segments_mem_idx_begin: &[0, 2, 7],
+    values: &[-500, -499, -2, -1, 0, 1, 2, 499, 500],
+};
+
+const _: () = assert!(ARRAY_1_U8.validate_const().is_ok());
+
+#[test]
+fn mem_idx_test_lookup() {
+    for index in 0..=200 {
+        assert_eq!(ARRAY_1_U8.get(index), ARRAY_1.get(index));
+        assert_eq!(ARRAY_1_U8.has_entry(index), ARRAY_1.has_entry(index));
+        assert_eq!(ARRAY_1_U8.floor(index), ARRAY_1.floor(index));
+        assert_eq!(ARRAY_1_U8.successor(index), ARRAY_1.successor(index));
+    }
+    for index in -600..=600 {
+        assert_eq!(ARRAY_4_U16.get(index), ARRAY_4.get(index));
+    }
+    assert_eq!(ARRAY_4_U16[499], 499);
+}
+
+#[test]
+fn mem_idx_test_iter() {
+    assert!(ARRAY_1_U8.entries().eq(ARRAY_1.entries()));
+    assert!(ARRAY_4_U16.entries().rev().eq(ARRAY_4.entries().rev()));
+    assert!(ARRAY_4_U16.indices().skip(3).eq(ARRAY_4.indices().skip(3)));
+    assert!(ARRAY_4_U16.range(-3..=499).eq(ARRAY_4.range(-3..=499)));
+    assert!(ARRAY_1_U8.segments().eq(ARRAY_1.segments()));
+
+    let mut entries = ARRAY_4_U16.entries();
+    entries.seek(0);
+    assert!(entries.eq(ARRAY_4.range(0..)));
+}
+
+#[test]
+fn mem_idx_test_mut() {
+    let mut values = [0u32; 6];
+    let mut array = NciArrayMut::with_layout(ARRAY_1_U8, &mut values);
+    array[10] = 10;
+    *array.get_mut(100).unwrap() = 100;
+    assert_eq!(array.get(10), Some(&10));
+    assert!(
+        array
+            .entries()
+            .map(|(index, _)| index)
+            .eq(ARRAY_1.indices())
+    );
+    assert_eq!(values, [0, 0, 0, 10, 0, 100]);
+}
+
+#[test]
+fn mem_idx_test_eytzinger() {
+    let eytzinger_array: NciEytzingerArray<u32, u32, u8> = NciEytzingerArray {
+        segments_idx_begin: &[10, 0, 100],
+        segments_mem_idx_begin: &[3, 0, 5],
+        values: &[0, 1, 2, 10, 11, 100],
+    };
+    assert_same_lookups!(eytzinger_array, ARRAY_1, &[3, 5, 9, 55, 99, 101, 200]);
+}
+
+#[test]
+fn mem_idx_test_buf() {
+    let buf = non_contiguously_indexed_array::NciArrayBuf::from(ARRAY_4_U16);
+    assert!(buf.as_array().entries().eq(ARRAY_4.entries()));
+}
@@ -0,0 +1,52 @@
+use non_contiguously_indexed_array::NciMultiArray;
+
+/// Indices 0 to 2 and 10 to 11, where index 1 has no values and index 10 has three, with `u8` memory indices of the values.
+const MULTI_ARRAY_1: NciMultiArray<u32, &str, u8> = NciMultiArray {
+    segments_idx_begin: &[0, 10],
+    segments_mem_idx_begin: &[0, 3],
+    values_begin: &[0, 1, 1, 2, 5],
+    values: &["zero", "two", "ten", "dix", "zehn", "eleven"],
+};
+
+#[test]
+fn multi_array_test_lookup() {
+    assert_eq!(MULTI_ARRAY_1.len(), 5);
+    assert_eq!(MULTI_ARRAY_1.get(0), Some(&["zero"][..]));
+    assert_eq!(MULTI_ARRAY_1.get(1), Some(&[][..]));
+    assert_eq!(MULTI_ARRAY_1.get(2), Some(&["two"][..]));
+    assert_eq!(MULTI_ARRAY_1.get(3), None);
+    assert_eq!(MULTI_ARRAY_1.get(10), Some(&["ten", "dix", "zehn"][..]));
+    assert_eq!(&MULTI_ARRAY_1[11], ["eleven"]);
+    assert_eq!(MULTI_ARRAY_1.get(12), None);
+    assert!(MULTI_ARRAY_1.has_entry(1));
+    assert!(!MULTI_ARRAY_1.has_entry(9));
+}
+
+#[test]
+fn multi_array_test_iter() {
+    let expected: [(u32, &[&str]); 5] = [
+        (0, &["zero"]),
+        (1, &[]),
+        (2, &["two"]),
+        (10, &["ten", "dix", "zehn"]),
+        (11, &["eleven"]),
+    ];
+    assert!(MULTI_ARRAY_1.indices().eq(expected.map(|(index, _)| index)));
+    assert!(
+        MULTI_ARRAY_1
+            .values()
+            .eq(expected.map(|(_, values)| values))
+    );
+    assert!(MULTI_ARRAY_1.entries().eq(expected));
+    assert!(MULTI_ARRAY_1.entries().rev().eq(expected.into_iter().rev()));
+    assert_eq!(MULTI_ARRAY_1.entries().len(), 5);
+}
+
+#[test]
+fn multi_array_test_empty() {
+    let array = NciMultiArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.entries().next(), None);
+}
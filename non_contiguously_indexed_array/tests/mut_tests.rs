@@ -0,0 +1,53 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciArrayMut;
+
+#[test]
+fn mut_array_test_1() {
+    let mut values = [0u32; 6];
+    let mut array = NciArrayMut::with_layout(ARRAY_1, &mut values);
+    for index in [0, 10, 10, 100, 100, 100] {
+        array[index] += 1;
+    }
+    assert_eq!(array.get_mut(5), None);
+    assert_eq!(array.get_mut(101), None);
+    *array.get_mut(2).unwrap() = 7;
+
+    assert_eq!(array[10], 2);
+    assert_eq!(array.get(100), Some(&3));
+    assert!(array.has_entry(11));
+    assert!(!array.has_entry(3));
+    assert_eq!(values, [1, 0, 7, 2, 0, 3]);
+}
+
+#[test]
+fn mut_array_test_2() {
+    let mut values = ARRAY_4.values.to_vec();
+    let mut array = NciArrayMut::with_layout(ARRAY_4, &mut values);
+    for value in array.values_mut() {
+        *value *= 2;
+    }
+    for (index, value) in array.entries_mut() {
+        *value -= index;
+    }
+    assert!(array.values().eq(ARRAY_4.values()));
+    assert!(array.indices().eq(ARRAY_4.indices()));
+    assert!(array.as_array().entries().eq(ARRAY_4.entries()));
+    assert_eq!(array.len(), ARRAY_4.len());
+}
+
+#[test]
+#[should_panic]
+fn mut_array_test_panic_on_length_mismatch() {
+    let mut values = [0u32; 5];
+    let _array = NciArrayMut::with_layout(ARRAY_1, &mut values);
+}
+
+#[test]
+#[should_panic]
+fn mut_array_test_panic_on_missing_index() {
+    let mut values = [0u32; 6];
+    let mut array = NciArrayMut::with_layout(ARRAY_1, &mut values);
+    array[5] = 1;
+}
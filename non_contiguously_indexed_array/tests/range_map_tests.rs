@@ -0,0 +1,72 @@
+use non_contiguously_indexed_array::NciRangeMap;
+
+/// Some Unicode blocks, with the range of "Latin-1 Supplement" directly following "Basic Latin".
+const BLOCKS: NciRangeMap<char, &str> = NciRangeMap {
+    ranges_idx_begin: &['\0', '\u{80}', '\u{370}', '\u{10000}'],
+    ranges_idx_end: &['\u{7F}', '\u{FF}', '\u{3FF}', '\u{1007F}'],
+    values: &[
+        "Basic Latin",
+        "Latin-1 Supplement",
+        "Greek and Coptic",
+        "Linear B Syllabary",
+    ],
+};
+
+#[test]
+fn range_map_test_lookup() {
+    assert_eq!(BLOCKS.len(), 4);
+    assert_eq!(BLOCKS.get('a'), Some(&"Basic Latin"));
+    assert_eq!(BLOCKS.get('\u{7F}'), Some(&"Basic Latin"));
+    assert_eq!(BLOCKS.get('\u{80}'), Some(&"Latin-1 Supplement"));
+    assert_eq!(BLOCKS.get('\u{100}'), None);
+    assert_eq!(BLOCKS.get('\u{36F}'), None);
+    assert_eq!(BLOCKS['λ'], "Greek and Coptic");
+    assert_eq!(BLOCKS.get('\u{400}'), None);
+    assert_eq!(BLOCKS.get('\u{1007F}'), Some(&"Linear B Syllabary"));
+    assert_eq!(BLOCKS.get(char::MAX), None);
+    assert!(BLOCKS.has_entry('\u{10000}'));
+    assert!(!BLOCKS.has_entry('\u{FFFF}'));
+
+    assert_eq!(BLOCKS.range_of('λ'), Some('\u{370}'..='\u{3FF}'));
+    assert_eq!(BLOCKS.range_of('\u{100}'), None);
+    assert_eq!(
+        BLOCKS.get_range_value('ÿ'),
+        Some(('\u{80}'..='\u{FF}', &"Latin-1 Supplement"))
+    );
+}
+
+#[test]
+fn range_map_test_iter() {
+    assert!(BLOCKS.values().eq(BLOCKS.values));
+    assert_eq!(BLOCKS.ranges().len(), 4);
+    assert_eq!(BLOCKS.ranges().nth(2), Some('\u{370}'..='\u{3FF}'));
+    assert_eq!(
+        BLOCKS.entries().next_back(),
+        Some(('\u{10000}'..='\u{1007F}', &"Linear B Syllabary"))
+    );
+    for (range, value) in BLOCKS.entries() {
+        assert_eq!(BLOCKS.get(*range.start()), Some(value));
+        assert_eq!(BLOCKS.get(*range.end()), Some(value));
+    }
+}
+
+#[test]
+fn range_map_test_long_range() {
+    // A single range longer than `usize::MAX`
+    let map: NciRangeMap<u128, bool> = NciRangeMap {
+        ranges_idx_begin: &[0, u128::MAX],
+        ranges_idx_end: &[u128::MAX - 1, u128::MAX],
+        values: &[false, true],
+    };
+    assert_eq!(map.get(u128::from(u64::MAX) << 8), Some(&false));
+    assert!(map[u128::MAX]);
+}
+
+#[test]
+fn range_map_test_empty() {
+    let map = NciRangeMap::<u8, u8>::new();
+    assert!(map.is_empty());
+    assert_eq!(map.get(0), None);
+    assert_eq!(map.range_of(u8::MAX), None);
+    assert_eq!(map.entries().next(), None);
+}
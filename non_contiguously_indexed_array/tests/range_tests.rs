@@ -0,0 +1,132 @@
+mod common;
+use common::*;
+
+use core::ops::{Bound, RangeBounds};
+
+use non_contiguously_indexed_array::{NciArray, NciIndex};
+
+fn assert_range_matches_filter<I, V, R>(array: NciArray<I, V>, range: R)
+where
+    I: NciIndex + std::fmt::Debug,
+    V: PartialEq + std::fmt::Debug,
+    R: RangeBounds<I> + Clone + std::fmt::Debug,
+{
+    let expected: Vec<_> = array
+        .entries()
+        .filter(|(index, _)| range.contains(index))
+        .collect();
+    let result = array.range(range.clone());
+    assert_eq!(result.len(), expected.len(), "{range:?}");
+    assert_eq!(result.collect::<Vec<_>>(), expected, "{range:?}");
+    assert!(
+        array
+            .range(range.clone())
+            .rev()
+            .eq(expected.iter().copied().rev()),
+        "{range:?}"
+    );
+
+    // Alternate between both ends
+    let mut result = array.range(range.clone());
+    let (mut front, mut back) = (0, expected.len());
+    while front < back {
+        if (front + back) % 2 == 0 {
+            assert_eq!(result.next(), Some(expected[front]));
+            front += 1;
+        } else {
+            back -= 1;
+            assert_eq!(result.next_back(), Some(expected[back]));
+        }
+        assert_eq!(result.len(), back - front);
+    }
+    assert_eq!(result.next(), None);
+    assert_eq!(result.next_back(), None);
+}
+
+macro_rules! range_test {
+    ($a:tt, $( $i:expr ),*) => {
+        let points = [$($i),*];
+        assert_range_matches_filter($a, ..);
+        for a in points {
+            assert_range_matches_filter($a, a..);
+            assert_range_matches_filter($a, ..a);
+            assert_range_matches_filter($a, ..=a);
+            assert_range_matches_filter($a, (Bound::Excluded(a), Bound::Unbounded));
+            for b in points {
+                assert_range_matches_filter($a, a..b);
+                assert_range_matches_filter($a, a..=b);
+                assert_range_matches_filter($a, (Bound::Excluded(a), Bound::Included(b)));
+                assert_range_matches_filter($a, (Bound::Excluded(a), Bound::Excluded(b)));
+            }
+        }
+    };
+}
+
+#[test]
+fn range_test_1() {
+    range_test!(ARRAY_1, 0, 1, 2, 3, 9, 10, 11, 12, 99, 100, 101, u32::MAX);
+}
+
+#[test]
+fn range_test_2() {
+    range_test!(ARRAY_2, 0, 99, 100, 101, 102, 200, 201, 499, 500, 502, 503);
+}
+
+#[test]
+fn range_test_3() {
+    range_test!(
+        ARRAY_3,
+        i32::MIN,
+        -501,
+        -500,
+        -499,
+        -498,
+        -497,
+        -491,
+        -490,
+        -400,
+        0
+    );
+}
+
+#[test]
+fn range_test_4() {
+    range_test!(
+        ARRAY_4,
+        -501,
+        -500,
+        -499,
+        -3,
+        -2,
+        0,
+        2,
+        3,
+        498,
+        499,
+        500,
+        i32::MAX
+    );
+}
+
+#[test]
+fn range_test_5() {
+    range_test!(
+        ARRAY_5,
+        0,
+        1,
+        2,
+        u128::MAX / 2 - 1,
+        u128::MAX / 2,
+        u128::MAX / 2 + 2,
+        u128::MAX - 2,
+        u128::MAX - 1,
+        u128::MAX
+    );
+}
+
+#[test]
+fn range_test_empty_array() {
+    let array = NciArray::<u8, u8>::new();
+    assert_eq!(array.range(..).next(), None);
+    assert_eq!(array.range(1..=5).next_back(), None);
+}
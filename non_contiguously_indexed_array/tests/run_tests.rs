@@ -0,0 +1,85 @@
+use non_contiguously_indexed_array::NciRunArray;
+
+/// The runs 0 to 4 and 5 to 9 directly follow each other,
+/// while the runs 5 to 9 and 20 to 22 have equal values, but are not contiguous.
+const RUN_ARRAY_1: NciRunArray<u32, char> = NciRunArray {
+    runs_idx_begin: &[0, 5, 20, 23],
+    runs_entry_begin: &[0, 5, 10, 13],
+    len: 14,
+    values: &['a', 'b', 'b', 'c'],
+};
+
+fn expected_value(index: u32) -> Option<char> {
+    match index {
+        0..=4 => Some('a'),
+        5..=9 | 20..=22 => Some('b'),
+        23 => Some('c'),
+        _ => None,
+    }
+}
+
+fn expected_entries() -> Vec<(u32, char)> {
+    (0..=u8::MAX.into())
+        .filter_map(|index| Some((index, expected_value(index)?)))
+        .collect()
+}
+
+#[test]
+fn run_array_test_lookup() {
+    assert_eq!(RUN_ARRAY_1.len(), 14);
+    for index in (0..=u8::MAX.into()).chain([u32::MAX]) {
+        assert_eq!(
+            RUN_ARRAY_1.get(index).copied(),
+            expected_value(index),
+            "{index}"
+        );
+        assert_eq!(
+            RUN_ARRAY_1.has_entry(index),
+            expected_value(index).is_some()
+        );
+    }
+    assert_eq!(RUN_ARRAY_1[21], 'b');
+    assert!(RUN_ARRAY_1.run_values().eq(RUN_ARRAY_1.values));
+}
+
+#[test]
+fn run_array_test_iter() {
+    let expected = expected_entries();
+    assert!(
+        RUN_ARRAY_1
+            .indices()
+            .eq(expected.iter().map(|(index, _)| *index))
+    );
+    assert!(
+        RUN_ARRAY_1
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(expected.iter().copied())
+    );
+    assert!(
+        RUN_ARRAY_1
+            .entries()
+            .rev()
+            .map(|(index, value)| (index, *value))
+            .eq(expected.iter().rev().copied())
+    );
+
+    let mut entries = RUN_ARRAY_1.entries();
+    assert_eq!(entries.next_back(), Some((23, &'c')));
+    assert_eq!(entries.next_back(), Some((22, &'b')));
+    assert_eq!(entries.nth(4), Some((4, &'a')));
+    assert_eq!(entries.next(), Some((5, &'b')));
+    assert_eq!(entries.len(), 6);
+    assert_eq!(entries.clone().nth(5), Some((21, &'b')));
+    assert_eq!(entries.nth_back(5), Some((6, &'b')));
+    assert_eq!(entries.next(), None);
+}
+
+#[test]
+fn run_array_test_empty() {
+    let array = NciRunArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.entries().next(), None);
+}
@@ -0,0 +1,98 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciSegmentLenArray;
+
+/// `ARRAY_1` with segment lengths.
+const SEGMENT_LEN_ARRAY_1: NciSegmentLenArray<u32, u32> = NciSegmentLenArray {
+    segments_idx_begin: &[0, 10, 100],
+    segments_len: &[3, 2, 1],
+    mem_idx_checkpoints: &[0],
+    values: &[0, 1, 2, 10, 11, 100],
+};
+
+/// `ARRAY_4` with segment lengths.
+const SEGMENT_LEN_ARRAY_4: NciSegmentLenArray<i32, i32> = NciSegmentLenArray {
+    segments_idx_begin: &[-500, -2, 499],
+    segments_len: &[2, 5, 2],
+    mem_idx_checkpoints: &[0],
+    values: &[-500, -499, -2, -1, 0, 1, 2, 499, 500],
+};
+
+#[test]
+fn segment_len_array_test_1() {
+    assert_same_lookups!(
+        SEGMENT_LEN_ARRAY_1,
+        ARRAY_1,
+        &[3, 5, 9, 12, 55, 99, 101, 500, u32::MAX],
+    );
+}
+
+#[test]
+fn segment_len_array_test_4() {
+    assert_same_lookups!(
+        SEGMENT_LEN_ARRAY_4,
+        ARRAY_4,
+        &[i32::MIN, -510, -501, -498, -250, -3, 3, 250, 498, 501, 999],
+    );
+}
+
+#[test]
+fn segment_len_array_test_checkpoints() {
+    // 40 segments of 3 entries each, so there are three checkpoints
+    let segments_idx_begin: Vec<u16> = (0..40).map(|segment| segment * 10).collect();
+    let values: Vec<u16> = segments_idx_begin
+        .iter()
+        .flat_map(|segment_idx_begin| *segment_idx_begin..segment_idx_begin + 3)
+        .collect();
+    let array = NciSegmentLenArray {
+        segments_idx_begin: &segments_idx_begin,
+        segments_len: &[3; 40],
+        mem_idx_checkpoints: &[0, 48, 96],
+        values: &values,
+    };
+    for index in 0..500 {
+        let expected = (index < 400 && index % 10 < 3).then_some(&index);
+        assert_eq!(array.get(index), expected, "{index}");
+    }
+    assert!(array.indices().eq(values.iter().copied()));
+}
+
+#[test]
+fn segment_len_array_test_split_segment() {
+    // A single contiguous run of 300 entries, split into two segments
+    let values: Vec<u16> = (1000..1300).collect();
+    let array = NciSegmentLenArray {
+        segments_idx_begin: &[1000, 1255],
+        segments_len: &[255, 45],
+        mem_idx_checkpoints: &[0],
+        values: &values,
+    };
+    for index in 900..1400 {
+        let expected = (1000..1300).contains(&index).then_some(&index);
+        assert_eq!(array.get(index), expected, "{index}");
+    }
+    assert!(array.indices().eq(1000..1300));
+    assert!(array.indices().rev().eq((1000..1300).rev()));
+
+    // Alternating between the front and the back, which meet within the first segment
+    let mut indices = array.indices();
+    assert_eq!(indices.nth_back(100), Some(1199));
+    assert_eq!(indices.len(), 199);
+    assert_eq!(indices.next(), Some(1000));
+    assert!(indices.by_ref().rev().take(197).eq((1002..1199).rev()));
+    assert_eq!(indices.next_back(), Some(1001));
+    assert_eq!(indices.len(), 0);
+    assert_eq!(indices.next(), None);
+    assert_eq!(indices.next_back(), None);
+}
+
+#[test]
+fn segment_len_array_test_empty() {
+    let array = NciSegmentLenArray::<u8, u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.entries().next(), None);
+    assert_eq!(array.indices().next_back(), None);
+}
@@ -0,0 +1,72 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::{NciArray, NciIndex, NciSegment};
+
+fn assert_segments_cover_entries<I: NciIndex + std::fmt::Debug, V: PartialEq + std::fmt::Debug>(
+    array: NciArray<I, V>,
+) {
+    let mut entries = array.entries();
+    for segment in array.segments() {
+        assert_eq!(segment.len(), segment.values.len());
+        assert!(!segment.is_empty());
+        let mut index = segment.start;
+        for value in segment.values {
+            assert_eq!(entries.next(), Some((index, value)));
+            if index != segment.end_inclusive {
+                index = index.next().unwrap();
+            }
+        }
+        assert_eq!(index, segment.end_inclusive);
+        assert!(segment.range().contains(&index));
+    }
+    assert_eq!(entries.next(), None);
+    assert!(
+        array
+            .segments()
+            .rev()
+            .eq(array.segments().collect::<Vec<_>>().into_iter().rev())
+    );
+}
+
+#[test]
+fn segment_test_1() {
+    assert_eq!(
+        ARRAY_1.segments().collect::<Vec<_>>(),
+        [
+            NciSegment {
+                start: 0,
+                end_inclusive: 2,
+                values: &[0, 1, 2],
+            },
+            NciSegment {
+                start: 10,
+                end_inclusive: 11,
+                values: &[10, 11],
+            },
+            NciSegment {
+                start: 100,
+                end_inclusive: 100,
+                values: &[100],
+            },
+        ]
+    );
+    assert_eq!(ARRAY_1.segments().len(), 3);
+    assert_eq!(
+        ARRAY_4
+            .segments()
+            .map(|segment| segment.range())
+            .collect::<Vec<_>>(),
+        [-500..=-499, -2..=2, 499..=500]
+    );
+    assert_eq!(NciArray::<u8, u8>::new().segments().next(), None);
+}
+
+#[test]
+fn segment_test_2() {
+    assert_segments_cover_entries(ARRAY_1);
+    assert_segments_cover_entries(ARRAY_2);
+    assert_segments_cover_entries(ARRAY_3);
+    assert_segments_cover_entries(ARRAY_4);
+    assert_segments_cover_entries(ARRAY_5);
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeSet;
+
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciSet;
+
+/// The indices of `ARRAY_1`.
+const SET_1: NciSet<u32> = NciSet {
+    segments_idx_begin: &[0, 10, 100],
+    segments_len: &[3, 2, 1],
+};
+
+const SET_A: NciSet<u8> = NciSet {
+    segments_idx_begin: &[0, 10, 20, 250],
+    segments_len: &[5, 6, 1, 6],
+};
+
+const SET_B: NciSet<u8> = NciSet {
+    segments_idx_begin: &[3, 7, 12, 16, 21, 240],
+    segments_len: &[2, 2, 2, 4, 1, 11],
+};
+
+fn expand(ranges: impl Iterator<Item = std::ops::RangeInclusive<u8>>) -> Vec<u8> {
+    ranges.flatten().collect()
+}
+
+#[test]
+fn set_test_1() {
+    assert_eq!(SET_1.len(), ARRAY_1.len());
+    assert!(!SET_1.is_empty());
+    assert!(SET_1.iter().eq(ARRAY_1.indices()));
+    assert!(SET_1.iter().rev().eq(ARRAY_1.indices().rev()));
+    let mut iter = SET_1.iter();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next_back(), Some(100));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 4);
+    assert!(iter.eq([1, 2, 10, 11]));
+    assert!(SET_1.ranges().eq([0..=2, 10..=11, 100..=100]));
+    for index in 0..=200 {
+        assert_eq!(SET_1.contains(index), ARRAY_1.has_entry(index), "{index}");
+    }
+    assert!(!SET_1.contains(u32::MAX));
+}
+
+#[test]
+fn set_test_algebra() {
+    let a: BTreeSet<u8> = SET_A.iter().collect();
+    let b: BTreeSet<u8> = SET_B.iter().collect();
+
+    assert_eq!(
+        expand(SET_A.union(&SET_B)),
+        a.union(&b).copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        expand(SET_A.intersection(&SET_B)),
+        a.intersection(&b).copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        expand(SET_A.difference(&SET_B)),
+        a.difference(&b).copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        expand(SET_B.difference(&SET_A)),
+        b.difference(&a).copied().collect::<Vec<_>>()
+    );
+
+    // Overlapping and adjacent ranges are merged, including ranges ending at `u8::MAX`
+    assert!(SET_A.union(&SET_B).eq([0..=4, 7..=8, 10..=21, 240..=255]));
+    assert!(SET_A.intersection(&SET_B).eq([3..=4, 12..=13, 250..=250]));
+    assert!(
+        SET_A
+            .difference(&SET_B)
+            .eq([0..=2, 10..=11, 14..=15, 20..=20, 251..=255])
+    );
+
+    assert!(!SET_A.is_disjoint(&SET_B));
+    assert!(SET_A.is_disjoint(&NciSet::new()));
+    assert!(!SET_A.is_subset(&SET_B));
+    assert!(SET_A.is_subset(&SET_A));
+    assert!(NciSet::new().is_subset(&SET_A));
+}
+
+#[test]
+fn set_test_empty() {
+    let set = NciSet::<u8>::new();
+    assert!(set.is_empty());
+    assert_eq!(set.len(), 0);
+    assert!(!set.contains(0));
+    assert_eq!(set.iter().next(), None);
+    assert_eq!(set.ranges().next(), None);
+    assert!(set.union(&SET_A).eq(SET_A.ranges()));
+    assert_eq!(set.intersection(&SET_A).next(), None);
+    assert_eq!(set.difference(&SET_A).next(), None);
+    assert!(SET_A.difference(&set).eq(SET_A.ranges()));
+}
@@ -0,0 +1,59 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::NciStrArray;
+
+/// The segment layout of `ARRAY_1`, with the name of each index as its value.
+const STR_ARRAY_1: NciStrArray<u32, u8> = NciStrArray {
+    segments_idx_begin: &[0, 10, 100],
+    segments_mem_idx_begin: &[0, 3, 5],
+    values_begin: &[0, 4, 7, 10, 13, 19],
+    values: "zeroonetwotenelevenhundred",
+};
+
+/// Values that are empty or not ASCII.
+const STR_ARRAY_2: NciStrArray<i8> = NciStrArray {
+    segments_idx_begin: &[-1],
+    segments_mem_idx_begin: &[0],
+    values_begin: &[0, 0, 7],
+    values: "größeäh",
+};
+
+#[test]
+fn str_array_test_1() {
+    let expected = [
+        (0, "zero"),
+        (1, "one"),
+        (2, "two"),
+        (10, "ten"),
+        (11, "eleven"),
+        (100, "hundred"),
+    ];
+    assert_eq!(STR_ARRAY_1.len(), 6);
+    assert!(STR_ARRAY_1.entries().eq(expected));
+    assert!(STR_ARRAY_1.entries().rev().eq(expected.into_iter().rev()));
+    assert!(STR_ARRAY_1.indices().eq(ARRAY_1.indices()));
+    for index in 0..=200 {
+        let expected_value = expected_value(&expected, index).copied();
+        assert_eq!(STR_ARRAY_1.get(index), expected_value, "{index}");
+        assert_eq!(STR_ARRAY_1.has_entry(index), ARRAY_1.has_entry(index));
+    }
+    assert_eq!(&STR_ARRAY_1[11], "eleven");
+}
+
+#[test]
+fn str_array_test_2() {
+    assert!(STR_ARRAY_2.values().eq(["", "größe", "äh"]));
+    assert_eq!(STR_ARRAY_2.get(0), Some("größe"));
+    assert_eq!(STR_ARRAY_2.get(1), Some("äh"));
+    assert_eq!(STR_ARRAY_2.get(2), None);
+}
+
+#[test]
+fn str_array_test_empty() {
+    let array = NciStrArray::<u8>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.get(0), None);
+    assert!(!array.has_entry(u8::MAX));
+    assert_eq!(array.entries().next(), None);
+}
@@ -0,0 +1,188 @@
+mod common;
+use common::*;
+
+use non_contiguously_indexed_array::{NciArray, NciLayoutError, NciMemIdx};
+
+const _: () = assert!(ARRAY_1.validate_const().is_ok());
+const _: () = assert!(ARRAY_5.validate_const().is_ok());
+
+macro_rules! validation_test_case {
+    ($t:ty, $idx:expr, $mem:expr, $values:expr, $expected:expr) => {
+        let array = NciArray::<$t, u8> {
+            segments_idx_begin: &$idx,
+            segments_mem_idx_begin: &$mem,
+            values: &$values,
+        };
+        assert_eq!(array.validate(), $expected);
+        assert_eq!(array.validate_const(), $expected);
+        assert_eq!(
+            NciArray::<$t, u8>::try_from_parts(&$idx, &$mem, &$values).map(|_| ()),
+            $expected
+        );
+    };
+}
+
+#[test]
+fn validation_test_valid() {
+    assert_eq!(ARRAY_1.validate(), Ok(()));
+    assert_eq!(ARRAY_2.validate(), Ok(()));
+    assert_eq!(ARRAY_3.validate(), Ok(()));
+    assert_eq!(ARRAY_4.validate_const(), Ok(()));
+    assert_eq!(ARRAY_5.validate(), Ok(()));
+    assert_eq!(NciArray::<u8, u8>::new().validate(), Ok(()));
+
+    validation_test_case!(u8, [0, 250], [0, 2], [0; 8], Ok(()));
+    validation_test_case!(i8, [-128, 0], [0, 1], [0; 128], Ok(()));
+}
+
+#[test]
+fn validation_test_mem_layout() {
+    validation_test_case!(
+        u8,
+        [0, 10],
+        [0],
+        [0; 3],
+        Err(NciLayoutError::SegmentCountMismatch {
+            segments_idx_begin_len: 2,
+            segments_mem_idx_begin_len: 1,
+        })
+    );
+    validation_test_case!(u8, [], [], [0; 3], Err(NciLayoutError::MissingSegments));
+    validation_test_case!(
+        u8,
+        [0, 10],
+        [1, 2],
+        [0; 3],
+        Err(NciLayoutError::FirstMemIdxNotZero { mem_idx: 1 })
+    );
+    validation_test_case!(
+        u8,
+        [0, 10],
+        [0, 3],
+        [0; 3],
+        Err(NciLayoutError::MemIdxOutOfBounds { segment: 1 })
+    );
+    validation_test_case!(
+        u8,
+        [0],
+        [0],
+        [],
+        Err(NciLayoutError::MemIdxOutOfBounds { segment: 0 })
+    );
+    validation_test_case!(
+        u8,
+        [0, 10, 20],
+        [0, 2, 2],
+        [0; 3],
+        Err(NciLayoutError::UnsortedMemIdx { segment: 2 })
+    );
+}
+
+#[test]
+fn validation_test_idx_layout() {
+    validation_test_case!(
+        u8,
+        [0, 250],
+        [0, 2],
+        [0; 9],
+        Err(NciLayoutError::SegmentOverflow { segment: 1 })
+    );
+    validation_test_case!(
+        i8,
+        [-128, 0],
+        [0, 1],
+        [0; 130],
+        Err(NciLayoutError::SegmentOverflow { segment: 1 })
+    );
+    validation_test_case!(
+        u8,
+        [10, 5],
+        [0, 2],
+        [0; 3],
+        Err(NciLayoutError::UnsortedIdx { segment: 1 })
+    );
+    validation_test_case!(
+        u8,
+        [10, 11],
+        [0, 2],
+        [0; 3],
+        Err(NciLayoutError::UnsortedIdx { segment: 1 })
+    );
+    validation_test_case!(
+        i8,
+        [-10, -8],
+        [0, 2],
+        [0; 3],
+        Err(NciLayoutError::AdjacentSegments { segment: 1 })
+    );
+}
+
+#[test]
+fn validation_test_error_display() {
+    assert_eq!(
+        NciLayoutError::AdjacentSegments { segment: 3 }.to_string(),
+        "segment 3 begins directly after the previous segment and should be merged with it"
+    );
+}
+
+#[test]
+fn validation_test_narrow_mem_idx() {
+    let array = NciArray::<u8, u8, u8>::try_from_parts(&[0, 250], &[0, 2], &[0; 8]);
+    assert!(array.is_ok());
+    let array = NciArray::<u8, u8, u16> {
+        segments_idx_begin: &[0, 10],
+        segments_mem_idx_begin: &[0, 300],
+        values: &[0; 300],
+    };
+    assert_eq!(
+        array.validate_mem_layout(),
+        Err(NciLayoutError::MemIdxOutOfBounds { segment: 1 })
+    );
+    assert_eq!(
+        array.validate_const(),
+        Err(NciLayoutError::MemIdxOutOfBounds { segment: 1 })
+    );
+    let array = NciArray::<i32, u8, u32> {
+        segments_idx_begin: &[0, 10],
+        segments_mem_idx_begin: &[0, 11],
+        values: &[0; 12],
+    };
+    assert_eq!(
+        array.validate(),
+        Err(NciLayoutError::UnsortedIdx { segment: 1 })
+    );
+}
+
+#[test]
+fn validation_test_custom_mem_idx() {
+    /// A memory index stored in 3 bytes.
+    #[derive(Clone, Copy)]
+    struct MemIdx24([u8; 3]);
+
+    impl NciMemIdx for MemIdx24 {
+        fn to_usize(self) -> usize {
+            let [a, b, c] = self.0;
+            (usize::from(a) << 16) | (usize::from(b) << 8) | usize::from(c)
+        }
+    }
+
+    let array = NciArray::<u8, u8, MemIdx24>::try_from_parts(
+        &[0, 10],
+        &[MemIdx24([0, 0, 0]), MemIdx24([0, 0, 3])],
+        &[0; 5],
+    );
+    assert!(array.is_ok_and(|array| array.get(11) == Some(&0)));
+    let array = NciArray::<u8, u8, MemIdx24> {
+        segments_idx_begin: &[0, 10],
+        segments_mem_idx_begin: &[MemIdx24([0, 0, 0]), MemIdx24([0, 1, 0])],
+        values: &[0; 5],
+    };
+    assert_eq!(
+        array.validate_mem_layout(),
+        Err(NciLayoutError::MemIdxOutOfBounds { segment: 1 })
+    );
+    assert_eq!(
+        array.validate(),
+        Err(NciLayoutError::MemIdxOutOfBounds { segment: 1 })
+    );
+}
@@ -1,4 +1,8 @@
-use non_contiguously_indexed_array::NciIndex;
+use std::fmt::Write as _;
+
+use non_contiguously_indexed_array::{NciBitmapArray, NciIndex, NciSegmentLenArray};
+
+use crate::output::{FormattedValue, OutputWriter};
 
 pub struct NciArrayBuilder<I: NciIndex, V> {
     entries: Vec<(I, V)>,
@@ -53,144 +57,470 @@ impl<I: NciIndex + std::fmt::Debug, V: std::fmt::Display + std::fmt::Debug> NciA
         }
     }
 
+    /// Returns the user-defined index and the memory index of the first element of each segment.
+    /// Requires the entries to be sorted.
+    fn segments(&self) -> (Vec<I>, Vec<usize>) {
+        segments_of(self.entries.iter().map(|(index, _value)| *index))
+    }
+
+    fn write_values(&self, writer: &mut OutputWriter, build_config: &BuildConfiguration) {
+        writer.array_field(
+            "values",
+            self.entries
+                .iter()
+                .map(|(_, value)| FormattedValue::new(value, &build_config.value_formatting)),
+        );
+    }
+
+    /// Writes the fields of a `non_contiguously_indexed_array::NciArray`.
+    fn write_array_fields(
+        &self,
+        writer: &mut OutputWriter,
+        build_config: &BuildConfiguration,
+        segments_idx_begin: &[I],
+        segments_mem_idx_begin: &[usize],
+    ) {
+        writer.index_array_field("segments_idx_begin", segments_idx_begin);
+        writer.array_field("segments_mem_idx_begin", segments_mem_idx_begin.iter());
+        self.write_values(writer, build_config);
+    }
+
+    /// Returns the name of the smallest unsigned integer type that can store the memory indices
+    /// of the segments of the output of [`Self::build`] and similar methods,
+    /// e.g., `"u16"` for `NciArray<'_, I, V, u16>`.
+    /// The output only consists of untyped integer literals, so the type of the memory indices is the one
+    /// in the type annotation of the constant the output is assigned to, which is `usize` if it is omitted.
+    /// To use the smallest type, the caller has to write the returned name into that annotation.
+    pub fn mem_idx_type(&mut self) -> &'static str {
+        self.ensure_output_preconditions();
+        let (_, segments_mem_idx_begin) = self.segments();
+        smallest_mem_idx_type(segments_mem_idx_begin.last().copied().unwrap_or(0))
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciArray`.
+    /// See [`Self::mem_idx_type`] for the smallest possible type of the memory indices,
+    /// which has to be written into the type annotation of the output.
     pub fn build(&mut self, build_config: &BuildConfiguration) -> String {
-        use std::fmt::Write as _;
+        self.ensure_output_preconditions();
+        let (segments_idx_begin, segments_mem_idx_begin) = self.segments();
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        self.write_array_fields(
+            &mut writer,
+            build_config,
+            &segments_idx_begin,
+            &segments_mem_idx_begin,
+        );
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciEytzingerArray`,
+    /// which stores its segments in Eytzinger order for more cache-friendly lookups.
+    pub fn build_eytzinger(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+        let (segments_idx_begin, segments_mem_idx_begin) = self.segments();
+        let order = eytzinger_order(segments_idx_begin.len());
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field(
+            "segments_idx_begin",
+            order.iter().map(|segment| &segments_idx_begin[*segment]),
+        );
+        writer.array_field(
+            "segments_mem_idx_begin",
+            order.iter().map(|segment| segments_mem_idx_begin[*segment]),
+        );
+        self.write_values(&mut writer, build_config);
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciBucketArray`,
+    /// which bounds the search for the segment of an index using a bucket table.
+    /// The bucket size is chosen such that there are at most as many buckets as segments.
+    pub fn build_bucketed(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+        let (segments_idx_begin, segments_mem_idx_begin) = self.segments();
+        let (bucket_shift, bucket_segments) = bucket_table(&segments_idx_begin);
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.struct_field(
+            "array",
+            "::non_contiguously_indexed_array::NciArray",
+            |writer| {
+                self.write_array_fields(
+                    writer,
+                    build_config,
+                    &segments_idx_begin,
+                    &segments_mem_idx_begin,
+                );
+            },
+        );
+        writer.value_field("bucket_shift", bucket_shift);
+        writer.array_field("bucket_segments", bucket_segments.iter());
+        writer.end_struct();
+        writer.finish()
+    }
 
+    /// Builds a `non_contiguously_indexed_array::NciBitmapArray`,
+    /// which stores which indices have an entry in a bitmap instead of segments.
+    /// The bitmap has a bit for every index from the lowest to the highest index, not just for the entries,
+    /// so sparse entries can require a very large amount of memory,
+    /// e.g., 512 MiB for just the two entries `0` and `u32::MAX`.
+    ///
+    /// # Panics
+    /// Panics if the distance between the lowest and the highest index exceeds `usize::MAX`.
+    pub fn build_bitmap(&mut self, build_config: &BuildConfiguration) -> String {
         self.ensure_output_preconditions();
+        let idx_begin = self.entries.first().map(|(index, _)| *index);
+        let mut bitmap: Vec<u64> = Vec::new();
+        if let Some(idx_begin) = idx_begin {
+            for (index, _) in &self.entries {
+                let distance = idx_begin.distance(*index).expect(
+                    "The distance between the lowest and the highest index exceeds usize::MAX",
+                );
+                let word = distance / 64;
+                if word >= bitmap.len() {
+                    bitmap.resize(word + 1, 0);
+                }
+                bitmap[word] |= 1 << (distance % 64);
+            }
+        }
+        let rank_samples = bitmap
+            .chunks(NciBitmapArray::<I, V>::WORDS_PER_RANK_SAMPLE)
+            .scan(0, |rank, words| {
+                let rank_sample = *rank;
+                *rank += words
+                    .iter()
+                    .map(|word| word.count_ones() as usize)
+                    .sum::<usize>();
+                Some(rank_sample)
+            })
+            .collect::<Vec<_>>();
 
-        let mut segments_idx_begin = Vec::new();
-        let mut segments_mem_idx_begin = Vec::new();
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.optional_index_field("idx_begin", idx_begin.as_ref());
+        writer.array_field("bitmap", bitmap.iter());
+        writer.array_field("rank_samples", rank_samples.iter());
+        self.write_values(&mut writer, build_config);
+        writer.end_struct();
+        writer.finish()
+    }
 
-        for mem_idx in 0..self.entries.len() {
-            let new_segment = if mem_idx == 0 {
+    /// Builds a `non_contiguously_indexed_array::NciRunArray`,
+    /// which stores a single value for each run of contiguous indices with equal values.
+    /// Values are considered equal if they are formatted equally.
+    pub fn build_run_length(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+        let formatted_values: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(_, value)| {
+                FormattedValue::new(value, &build_config.value_formatting).to_string()
+            })
+            .collect();
+        let mut runs_idx_begin = Vec::new();
+        let mut runs_entry_begin = Vec::new();
+        for entry_idx in 0..self.entries.len() {
+            let new_run = if entry_idx == 0 {
                 true
             } else {
-                let prv_entry_idx = self.entries[mem_idx - 1].0;
-                let cur_entry_idx = self.entries[mem_idx].0;
+                let prv_entry_idx = self.entries[entry_idx - 1].0;
+                let cur_entry_idx = self.entries[entry_idx].0;
                 prv_entry_idx.distance(cur_entry_idx) != Some(1)
+                    || formatted_values[entry_idx - 1] != formatted_values[entry_idx]
             };
-            if new_segment {
-                segments_idx_begin.push(self.entries[mem_idx].0);
-                segments_mem_idx_begin.push(mem_idx);
+            if new_run {
+                runs_idx_begin.push(self.entries[entry_idx].0);
+                runs_entry_begin.push(entry_idx);
             }
         }
 
-        let (struct_opening_str, struct_closing_str, array_opening_str, array_closing_str) =
-            match build_config.output_format {
-                OutputFormat::RustCodegen => ("{", "}", "&[", "]"),
-                OutputFormat::RON | OutputFormat::RONPretty => ("(", ")", "(", ")"),
-            };
-        let (new_line_str, indentation_str, space_str) = match build_config.output_format {
-            OutputFormat::RON => ("", "", ""),
-            _ => ("\n", "\t", " "),
-        };
-
-        let mut output_string = format!("{struct_opening_str}{new_line_str}");
-
-        write!(
-            output_string,
-            "{indentation_str}segments_idx_begin:{space_str}{array_opening_str}{new_line_str}"
-        )
-        .unwrap();
-        for (i, idx_begin) in segments_idx_begin.iter().enumerate() {
-            let comma_str = match build_config.output_format {
-                OutputFormat::RON => {
-                    if i == segments_idx_begin.len() - 1 {
-                        ""
-                    } else {
-                        ","
-                    }
-                }
-                _ => ",",
-            };
-            write!(
-                output_string,
-                "{indentation_str}{indentation_str}{:?}{comma_str}{new_line_str}",
-                *idx_begin
-            )
-            .unwrap();
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field("runs_idx_begin", &runs_idx_begin);
+        writer.array_field("runs_entry_begin", runs_entry_begin.iter());
+        writer.value_field("len", self.entries.len());
+        writer.array_field(
+            "values",
+            runs_entry_begin
+                .iter()
+                .map(|entry_idx| &formatted_values[*entry_idx]),
+        );
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciSegmentLenArray`,
+    /// which stores the length of each segment instead of the memory index of its first element.
+    /// Segments longer than `u8::MAX` are split into multiple segments.
+    pub fn build_segment_len(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+        let (segments_idx_begin, segments_mem_idx_begin) = self.segments();
+        let mut split_segments_idx_begin = Vec::new();
+        let mut split_segments_len = Vec::new();
+        for (segment, segment_idx_begin) in segments_idx_begin.iter().enumerate() {
+            let segment_mem_idx_end = segments_mem_idx_begin
+                .get(segment + 1)
+                .copied()
+                .unwrap_or(self.entries.len());
+            let segment_len = segment_mem_idx_end - segments_mem_idx_begin[segment];
+            for split_mem_idx_begin in (0..segment_len).step_by(usize::from(u8::MAX)) {
+                // Every split segment begins at an index that has an entry, so `advance` cannot fail
+                split_segments_idx_begin
+                    .push(segment_idx_begin.advance(split_mem_idx_begin).unwrap());
+                split_segments_len
+                    .push((segment_len - split_mem_idx_begin).min(usize::from(u8::MAX)));
+            }
         }
-        write!(
-            output_string,
-            "{indentation_str}{array_closing_str},{new_line_str}"
-        )
-        .unwrap();
-
-        write!(
-            output_string,
-            "{indentation_str}segments_mem_idx_begin:{space_str}{array_opening_str}{new_line_str}"
-        )
-        .unwrap();
-        for (i, mem_idx_begin) in segments_mem_idx_begin.iter().enumerate() {
-            let comma_str = match build_config.output_format {
-                OutputFormat::RON => {
-                    if i == segments_mem_idx_begin.len() - 1 {
-                        ""
-                    } else {
-                        ","
-                    }
-                }
-                _ => ",",
-            };
-            write!(
-                output_string,
-                "{indentation_str}{indentation_str}{:?}{comma_str}{new_line_str}",
-                *mem_idx_begin
-            )
-            .unwrap();
+        let mem_idx_checkpoints = split_segments_len
+            .chunks(NciSegmentLenArray::<I, V>::SEGMENTS_PER_CHECKPOINT)
+            .scan(0, |mem_idx, segments_len| {
+                let mem_idx_checkpoint = *mem_idx;
+                *mem_idx += segments_len.iter().sum::<usize>();
+                Some(mem_idx_checkpoint)
+            })
+            .collect::<Vec<_>>();
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field("segments_idx_begin", &split_segments_idx_begin);
+        writer.array_field("segments_len", split_segments_len.iter());
+        writer.array_field("mem_idx_checkpoints", mem_idx_checkpoints.iter());
+        self.write_values(&mut writer, build_config);
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Returns the distinct formatted values in order of their first occurrence,
+    /// and for each entry the id of its value, i.e., the position of the value among the distinct values.
+    /// Requires the entries to be sorted.
+    fn interned_values(&self, build_config: &BuildConfiguration) -> (Vec<String>, Vec<usize>) {
+        let mut unique_values = Vec::new();
+        let mut value_ids = std::collections::HashMap::new();
+        let ids = self
+            .entries
+            .iter()
+            .map(|(_, value)| {
+                let value = FormattedValue::new(value, &build_config.value_formatting).to_string();
+                *value_ids.entry(value).or_insert_with_key(|value| {
+                    unique_values.push(value.clone());
+                    unique_values.len() - 1
+                })
+            })
+            .collect();
+        (unique_values, ids)
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciInternedArray`,
+    /// which stores each distinct value only once, with each entry storing the id of its value.
+    /// Values are considered equal if they are formatted equally.
+    /// See [`Self::interned_id_type`] for the smallest possible type of the ids,
+    /// which has to be written into the type annotation of the output.
+    pub fn build_interned(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+        let (segments_idx_begin, segments_mem_idx_begin) = self.segments();
+        let (unique_values, ids) = self.interned_values(build_config);
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field("segments_idx_begin", &segments_idx_begin);
+        writer.array_field("segments_mem_idx_begin", segments_mem_idx_begin.iter());
+        writer.array_field("ids", ids.iter());
+        writer.array_field("unique_values", unique_values.iter());
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Returns the distinct indices, and for each of them the memory index of its first value.
+    /// Sorts the entries by their index, keeping the values of each index in the order in which they were added.
+    fn multi_values_begin(&mut self) -> (Vec<I>, Vec<usize>) {
+        // The sort is stable, so the values of each index stay in the order in which they were added
+        self.entries.sort_by_key(|(index, _value)| *index);
+        let mut indices = Vec::new();
+        let mut values_begin = Vec::new();
+        for (value_mem_idx, (index, _value)) in self.entries.iter().enumerate() {
+            if indices.last() != Some(index) {
+                indices.push(*index);
+                values_begin.push(value_mem_idx);
+            }
         }
-        write!(
-            output_string,
-            "{indentation_str}{array_closing_str},{new_line_str}",
-        )
-        .unwrap();
-
-        write!(
-            output_string,
-            "{indentation_str}values:{space_str}{array_opening_str}{new_line_str}",
-        )
-        .unwrap();
-        for (i, (_, value)) in self.entries.iter().enumerate() {
-            let comma_str = match build_config.output_format {
-                OutputFormat::RON => {
-                    if i == self.entries.len() - 1 {
-                        ""
-                    } else {
-                        ","
-                    }
-                }
-                _ => ",",
-            };
-            let entry_str = match build_config.value_formatting {
-                ValueFormatting::Display => &format!(
-                    "{indentation_str}{indentation_str}{}{comma_str}{new_line_str}",
-                    *value
-                ),
-                ValueFormatting::Debug => &format!(
-                    "{indentation_str}{indentation_str}{:?}{comma_str}{new_line_str}",
-                    *value
-                ),
-                ValueFormatting::DisplayAlternate => &format!(
-                    "{indentation_str}{indentation_str}{:#}{comma_str}{new_line_str}",
-                    *value
-                ),
-                ValueFormatting::DebugAlternate => &format!(
-                    "{indentation_str}{indentation_str}{:#?}{comma_str}{new_line_str}",
-                    *value
-                ),
-            };
-            write!(output_string, "{entry_str}").unwrap();
+        (indices, values_begin)
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciMultiArray`, which stores a slice of values per index.
+    /// Unlike for the other build methods, multiple entries can have the same index,
+    /// in which case their values are stored in the order in which the entries were added.
+    /// See [`Self::multi_offset_type`] for the smallest possible type of the memory indices of the values,
+    /// which has to be written into the type annotation of the output.
+    pub fn build_multi(&mut self, build_config: &BuildConfiguration) -> String {
+        let (indices, values_begin) = self.multi_values_begin();
+        let (segments_idx_begin, segments_mem_idx_begin) = segments_of(indices);
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field("segments_idx_begin", &segments_idx_begin);
+        writer.array_field("segments_mem_idx_begin", segments_mem_idx_begin.iter());
+        writer.array_field("values_begin", values_begin.iter());
+        self.write_values(&mut writer, build_config);
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Returns the formatted values concatenated into a single string,
+    /// and for each entry the byte offset of its value within that string.
+    fn concatenated_values(&self, build_config: &BuildConfiguration) -> (String, Vec<usize>) {
+        let mut values = String::new();
+        let values_begin = self
+            .entries
+            .iter()
+            .map(|(_, value)| {
+                let value_begin = values.len();
+                write!(
+                    values,
+                    "{}",
+                    FormattedValue::new(value, &build_config.value_formatting)
+                )
+                .unwrap();
+                value_begin
+            })
+            .collect();
+        (values, values_begin)
+    }
+
+    /// Builds a `non_contiguously_indexed_array::NciStrArray`,
+    /// which concatenates the formatted values into a single string.
+    /// See [`Self::str_offset_type`] for the smallest possible type of the offsets,
+    /// which has to be written into the type annotation of the output.
+    pub fn build_str(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+        let (segments_idx_begin, segments_mem_idx_begin) = self.segments();
+        let (values, values_begin) = self.concatenated_values(build_config);
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field("segments_idx_begin", &segments_idx_begin);
+        writer.array_field("segments_mem_idx_begin", segments_mem_idx_begin.iter());
+        writer.array_field("values_begin", values_begin.iter());
+        // The `Debug` representation of a string is a valid string literal
+        writer.value_field("values", format!("{values:?}"));
+        writer.end_struct();
+        writer.finish()
+    }
+
+    /// Returns the name of the smallest unsigned integer type that can store the offsets
+    /// of the output of [`Self::build_str`] with the same configuration, e.g., `"u16"` for `NciStrArray<'_, I, u16>`.
+    /// As for [`Self::mem_idx_type`], the caller has to write the returned name into the type annotation
+    /// of the constant the output is assigned to.
+    pub fn str_offset_type(&mut self, build_config: &BuildConfiguration) -> &'static str {
+        self.ensure_output_preconditions();
+        let (_, values_begin) = self.concatenated_values(build_config);
+        smallest_mem_idx_type(values_begin.last().copied().unwrap_or(0))
+    }
+
+    /// Returns the name of the smallest unsigned integer type that can store the memory indices of the values
+    /// of the output of [`Self::build_multi`], e.g., `"u16"` for `NciMultiArray<'_, I, V, u16>`.
+    /// As for [`Self::mem_idx_type`], the caller has to write the returned name into the type annotation
+    /// of the constant the output is assigned to.
+    pub fn multi_offset_type(&mut self) -> &'static str {
+        let (_, values_begin) = self.multi_values_begin();
+        smallest_mem_idx_type(values_begin.last().copied().unwrap_or(0))
+    }
+
+    /// Returns the name of the smallest unsigned integer type that can store the value ids
+    /// of the output of [`Self::build_interned`] with the same configuration,
+    /// e.g., `"u8"` for `NciInternedArray<'_, I, V, u8>`.
+    /// As for [`Self::mem_idx_type`], the caller has to write the returned name into the type annotation
+    /// of the constant the output is assigned to, as the type of the ids is `usize` if it is omitted.
+    pub fn interned_id_type(&mut self, build_config: &BuildConfiguration) -> &'static str {
+        self.ensure_output_preconditions();
+        let (unique_values, _) = self.interned_values(build_config);
+        smallest_mem_idx_type(unique_values.len().saturating_sub(1))
+    }
+}
+
+/// Returns the user-defined index and the memory index of the first element of each segment
+/// of an array with the given indices, which have to be sorted and distinct.
+fn segments_of<I: NciIndex>(indices: impl IntoIterator<Item = I>) -> (Vec<I>, Vec<usize>) {
+    let mut segments_idx_begin = Vec::new();
+    let mut segments_mem_idx_begin = Vec::new();
+
+    let mut prv_entry_idx: Option<I> = None;
+    for (mem_idx, cur_entry_idx) in indices.into_iter().enumerate() {
+        let new_segment = prv_entry_idx
+            .is_none_or(|prv_entry_idx| prv_entry_idx.distance(cur_entry_idx) != Some(1));
+        if new_segment {
+            segments_idx_begin.push(cur_entry_idx);
+            segments_mem_idx_begin.push(mem_idx);
         }
-        let comma_str = match build_config.output_format {
-            OutputFormat::RON => "",
-            _ => ",",
-        };
-        write!(
-            output_string,
-            "{indentation_str}{array_closing_str}{comma_str}{new_line_str}"
-        )
-        .unwrap();
-        write!(output_string, "{struct_closing_str}").unwrap();
-        output_string
+        prv_entry_idx = Some(cur_entry_idx);
     }
+
+    (segments_idx_begin, segments_mem_idx_begin)
+}
+
+/// Returns the name of the smallest type implementing `non_contiguously_indexed_array::NciMemIdx`
+/// that can store the given memory index.
+fn smallest_mem_idx_type(max_mem_idx: usize) -> &'static str {
+    if u8::try_from(max_mem_idx).is_ok() {
+        "u8"
+    } else if u16::try_from(max_mem_idx).is_ok() {
+        "u16"
+    } else if u32::try_from(max_mem_idx).is_ok() {
+        "u32"
+    } else {
+        "usize"
+    }
+}
+
+/// Returns the bucket shift and the segment potentially containing the first index of each bucket
+/// for a bucket table with at most as many buckets as there are segments.
+fn bucket_table<I: NciIndex>(segments_idx_begin: &[I]) -> (u32, Vec<usize>) {
+    let (Some(first_idx), Some(last_idx)) = (segments_idx_begin.first(), segments_idx_begin.last())
+    else {
+        return (0, Vec::new());
+    };
+    let span = first_idx.distance(*last_idx).unwrap_or(usize::MAX);
+    // A shift by `usize::BITS` or more results in a single bucket
+    let last_bucket = |bucket_shift| span.checked_shr(bucket_shift).unwrap_or(0);
+    let mut bucket_shift = 0;
+    while last_bucket(bucket_shift) >= segments_idx_begin.len() {
+        bucket_shift += 1;
+    }
+
+    let bucket_count = last_bucket(bucket_shift) + 1;
+    let bucket_segments = (0..bucket_count)
+        .map(|bucket| {
+            // The first index of each bucket is at most `last_idx`, so `advance` cannot fail
+            let bucket_idx_begin = first_idx
+                .advance(bucket.checked_shl(bucket_shift).unwrap_or(0))
+                .unwrap();
+            segments_idx_begin
+                .partition_point(|segment_idx_begin| bucket_idx_begin.ge(segment_idx_begin))
+                - 1
+        })
+        .collect();
+    (bucket_shift, bucket_segments)
+}
+
+/// Returns the positions in sorted order of the elements of an Eytzinger layout of `len` elements,
+/// i.e., the layout of a complete binary search tree in breadth-first order.
+fn eytzinger_order(len: usize) -> Vec<usize> {
+    fn fill(order: &mut [usize], next_position: &mut usize, node: usize) {
+        if node <= order.len() {
+            fill(order, next_position, 2 * node);
+            order[node - 1] = *next_position;
+            *next_position += 1;
+            fill(order, next_position, 2 * node + 1);
+        }
+    }
+
+    let mut order = vec![0; len];
+    fill(&mut order, &mut 0, 1);
+    order
 }
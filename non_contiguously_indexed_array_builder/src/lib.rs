@@ -1,2 +1,10 @@
 mod builder;
 pub use builder::*;
+
+mod set_builder;
+pub use set_builder::*;
+
+mod range_map_builder;
+pub use range_map_builder::*;
+
+mod output;
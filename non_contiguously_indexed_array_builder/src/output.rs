@@ -0,0 +1,222 @@
+use std::fmt::{Display, Write as _};
+
+use non_contiguously_indexed_array::NciIndex;
+
+use crate::{BuildConfiguration, OutputFormat, ValueFormatting};
+
+/// Writes (nested) structs and arrays in the configured output format.
+pub struct OutputWriter<'a> {
+    build_config: &'a BuildConfiguration,
+    output: String,
+    /// For each currently open struct or array, whether an element has been written to it.
+    element_written: Vec<bool>,
+}
+
+impl<'a> OutputWriter<'a> {
+    pub const fn new(build_config: &'a BuildConfiguration) -> Self {
+        Self {
+            build_config,
+            output: String::new(),
+            element_written: Vec::new(),
+        }
+    }
+
+    pub fn finish(self) -> String {
+        self.output
+    }
+
+    /// Begins a struct. The type name is only required for nested structs in Rust codegen,
+    /// as the caller writes the type name of the outermost struct.
+    pub fn begin_struct(&mut self, type_name: Option<&str>) {
+        match (&self.build_config.output_format, type_name) {
+            (OutputFormat::RustCodegen, Some(type_name)) => {
+                write!(self.output, "{type_name} {{").unwrap();
+            }
+            (OutputFormat::RustCodegen, None) => self.output.push('{'),
+            (OutputFormat::RON | OutputFormat::RONPretty, _) => self.output.push('('),
+        }
+        self.open();
+    }
+
+    pub fn end_struct(&mut self) {
+        let struct_closing_str = match self.build_config.output_format {
+            OutputFormat::RustCodegen => "}",
+            OutputFormat::RON | OutputFormat::RONPretty => ")",
+        };
+        self.close(struct_closing_str);
+    }
+
+    /// Writes a struct field containing an array (a slice reference in Rust codegen).
+    pub fn array_field<T: Display>(&mut self, name: &str, elements: impl IntoIterator<Item = T>) {
+        let (array_opening_str, array_closing_str) = match self.build_config.output_format {
+            OutputFormat::RustCodegen => ("&[", "]"),
+            OutputFormat::RON | OutputFormat::RONPretty => ("(", ")"),
+        };
+        self.begin_field(name);
+        self.output.push_str(array_opening_str);
+        self.open();
+        for element in elements {
+            self.begin_element();
+            write!(self.output, "{element}").unwrap();
+            self.end_element();
+        }
+        self.close(array_closing_str);
+        self.end_element();
+    }
+
+    /// Writes a struct field containing a nested struct, whose fields are written by `write_fields`.
+    pub fn struct_field(
+        &mut self,
+        name: &str,
+        type_name: &str,
+        write_fields: impl FnOnce(&mut Self),
+    ) {
+        self.begin_field(name);
+        self.begin_struct(Some(type_name));
+        write_fields(self);
+        self.end_struct();
+        self.end_element();
+    }
+
+    /// Writes a struct field containing a single value.
+    pub fn value_field(&mut self, name: &str, value: impl Display) {
+        self.begin_field(name);
+        write!(self.output, "{value}").unwrap();
+        self.end_element();
+    }
+
+    /// Writes a struct field containing an array of indices.
+    pub fn index_array_field<'i, I: NciIndex + std::fmt::Debug + 'i>(
+        &mut self,
+        name: &str,
+        indices: impl IntoIterator<Item = &'i I>,
+    ) {
+        let output_format = &self.build_config.output_format;
+        self.array_field(
+            name,
+            indices.into_iter().map(|index| IndexLiteral {
+                index,
+                output_format,
+            }),
+        );
+    }
+
+    /// Writes a struct field containing an optional index, i.e., `Some(index)` or `None`.
+    pub fn optional_index_field<I: NciIndex + std::fmt::Debug>(
+        &mut self,
+        name: &str,
+        index: Option<&I>,
+    ) {
+        let output_format = &self.build_config.output_format;
+        match index {
+            Some(index) => self.value_field(
+                name,
+                format_args!(
+                    "Some({})",
+                    IndexLiteral {
+                        index,
+                        output_format,
+                    }
+                ),
+            ),
+            None => self.value_field(name, "None"),
+        }
+    }
+
+    fn begin_field(&mut self, name: &str) {
+        self.begin_element();
+        let space_str = self.space_str();
+        write!(self.output, "{name}:{space_str}").unwrap();
+    }
+
+    fn open(&mut self) {
+        let new_line_str = self.new_line_str();
+        self.output.push_str(new_line_str);
+        self.element_written.push(false);
+    }
+
+    fn close(&mut self, closing_str: &str) {
+        self.element_written.pop();
+        self.indent();
+        self.output.push_str(closing_str);
+    }
+
+    fn begin_element(&mut self) {
+        let is_compact = self.is_compact();
+        if let Some(element_written) = self.element_written.last_mut() {
+            if *element_written && is_compact {
+                self.output.push(',');
+            }
+            *element_written = true;
+        }
+        self.indent();
+    }
+
+    fn end_element(&mut self) {
+        if !self.is_compact() {
+            write!(self.output, ",{}", self.new_line_str()).unwrap();
+        }
+    }
+
+    fn indent(&mut self) {
+        let indentation_str = if self.is_compact() { "" } else { "\t" };
+        for _ in 0..self.element_written.len() {
+            self.output.push_str(indentation_str);
+        }
+    }
+
+    /// Whether the output is written without any whitespace, in which case there are no trailing commas.
+    const fn is_compact(&self) -> bool {
+        matches!(self.build_config.output_format, OutputFormat::RON)
+    }
+
+    const fn new_line_str(&self) -> &'static str {
+        if self.is_compact() { "" } else { "\n" }
+    }
+
+    const fn space_str(&self) -> &'static str {
+        if self.is_compact() { "" } else { " " }
+    }
+}
+
+/// Formats a value according to the configured `ValueFormatting`.
+pub struct FormattedValue<'a, V> {
+    value: &'a V,
+    value_formatting: &'a ValueFormatting,
+}
+
+impl<'a, V> FormattedValue<'a, V> {
+    pub const fn new(value: &'a V, value_formatting: &'a ValueFormatting) -> Self {
+        Self {
+            value,
+            value_formatting,
+        }
+    }
+}
+
+impl<V: std::fmt::Display + std::fmt::Debug> Display for FormattedValue<'_, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value_formatting {
+            ValueFormatting::Display => write!(f, "{}", self.value),
+            ValueFormatting::Debug => write!(f, "{:?}", self.value),
+            ValueFormatting::DisplayAlternate => write!(f, "{:#}", self.value),
+            ValueFormatting::DebugAlternate => write!(f, "{:#?}", self.value),
+        }
+    }
+}
+
+/// Formats an index as a literal, i.e., as a Rust expression (see [`NciIndex::fmt_rust_expr`]) in Rust codegen
+/// and using its `Debug` representation otherwise.
+struct IndexLiteral<'a, I> {
+    index: &'a I,
+    output_format: &'a OutputFormat,
+}
+
+impl<I: NciIndex + std::fmt::Debug> Display for IndexLiteral<'_, I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.output_format {
+            OutputFormat::RustCodegen => self.index.fmt_rust_expr(f),
+            OutputFormat::RON | OutputFormat::RONPretty => write!(f, "{:?}", self.index),
+        }
+    }
+}
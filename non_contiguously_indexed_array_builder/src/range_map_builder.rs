@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use non_contiguously_indexed_array::NciIndex;
+
+use crate::BuildConfiguration;
+use crate::output::{FormattedValue, OutputWriter};
+
+/// Builds a `non_contiguously_indexed_array::NciRangeMap`, i.e., a map from ranges of indices to values.
+pub struct NciRangeMapBuilder<I: NciIndex, V> {
+    entries: Vec<(RangeInclusive<I>, V)>,
+}
+
+impl<I: NciIndex + std::fmt::Debug, V: std::fmt::Display + std::fmt::Debug> Default
+    for NciRangeMapBuilder<I, V>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: NciIndex + std::fmt::Debug, V: std::fmt::Display + std::fmt::Debug>
+    NciRangeMapBuilder<I, V>
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Maps all indices within the given range to the value.
+    /// Panics in case the range is empty.
+    pub fn entry_range(&mut self, range: RangeInclusive<I>, value: V) {
+        assert!(
+            range.start() <= range.end(),
+            "Empty range detected! range: {range:?}; value: {value:?}"
+        );
+        self.entries.push((range, value));
+    }
+
+    /// Maps a single index to the value.
+    pub fn entry(&mut self, index: I, value: V) {
+        self.entry_range(index..=index, value);
+    }
+
+    fn ensure_output_preconditions(&mut self) {
+        self.entries.sort_by_key(|(range, _value)| *range.start());
+        for window in self.entries.windows(2) {
+            assert!(
+                window[0].0.end() < window[1].0.start(),
+                "Overlapping ranges detected! ranges: {:?}, {:?}; values: {:?}, {:?}",
+                window[0].0,
+                window[1].0,
+                window[0].1,
+                window[1].1
+            );
+        }
+    }
+
+    pub fn build(&mut self, build_config: &BuildConfiguration) -> String {
+        self.ensure_output_preconditions();
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field(
+            "ranges_idx_begin",
+            self.entries.iter().map(|(range, _)| range.start()),
+        );
+        writer.index_array_field(
+            "ranges_idx_end",
+            self.entries.iter().map(|(range, _)| range.end()),
+        );
+        writer.array_field(
+            "values",
+            self.entries
+                .iter()
+                .map(|(_, value)| FormattedValue::new(value, &build_config.value_formatting)),
+        );
+        writer.end_struct();
+        writer.finish()
+    }
+}
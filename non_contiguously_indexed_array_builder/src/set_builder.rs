@@ -0,0 +1,52 @@
+use non_contiguously_indexed_array::NciIndex;
+
+use crate::BuildConfiguration;
+use crate::output::OutputWriter;
+
+/// Builds a `non_contiguously_indexed_array::NciSet`, i.e., the equivalent of an `NciArray` without any values.
+pub struct NciSetBuilder<I: NciIndex> {
+    indices: Vec<I>,
+}
+
+impl<I: NciIndex + std::fmt::Debug> Default for NciSetBuilder<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: NciIndex + std::fmt::Debug> NciSetBuilder<I> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { indices: vec![] }
+    }
+
+    /// Adds an index to the set. Adding the same index multiple times has no further effect.
+    pub fn insert(&mut self, index: I) {
+        self.indices.push(index);
+    }
+
+    pub fn build(&mut self, build_config: &BuildConfiguration) -> String {
+        self.indices.sort_unstable();
+        self.indices.dedup();
+
+        let mut segments_idx_begin = Vec::new();
+        let mut segments_len: Vec<usize> = Vec::new();
+        for (i, index) in self.indices.iter().enumerate() {
+            let continues_segment = i > 0 && self.indices[i - 1].distance(*index) == Some(1);
+            match segments_len.last_mut() {
+                Some(segment_len) if continues_segment => *segment_len += 1,
+                _ => {
+                    segments_idx_begin.push(*index);
+                    segments_len.push(1);
+                }
+            }
+        }
+
+        let mut writer = OutputWriter::new(build_config);
+        writer.begin_struct(None);
+        writer.index_array_field("segments_idx_begin", &segments_idx_begin);
+        writer.array_field("segments_len", segments_len.iter());
+        writer.end_struct();
+        writer.finish()
+    }
+}
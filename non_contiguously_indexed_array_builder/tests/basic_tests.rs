@@ -1,11 +1,14 @@
 use std::{
     fs::File,
     io::{BufWriter, Write},
+    net::Ipv4Addr,
+    num::NonZeroU16,
     path,
 };
 
 use non_contiguously_indexed_array_builder::{
-    BuildConfiguration, NciArrayBuilder, OutputFormat, ValueFormatting,
+    BuildConfiguration, NciArrayBuilder, NciRangeMapBuilder, NciSetBuilder, OutputFormat,
+    ValueFormatting,
 };
 
 mod constants;
@@ -21,6 +24,9 @@ const DEFAUTLT_BUILD_CONFIGURATION: BuildConfiguration = BuildConfiguration {
 
 macro_rules! build_test_array {
     ( $id:literal, $iter:expr, $ty:tt, $conf:expr) => {
+        build_test_array!($id, $iter, $ty, $conf, NciArray, build);
+    };
+    ( $id:literal, $iter:expr, $ty:tt, $conf:expr, $array_ty:ident, $build_fn:ident) => {
         let mut builder = NciArrayBuilder::new();
         for (index, value) in $iter {
             builder.entry(index, value);
@@ -31,11 +37,14 @@ macro_rules! build_test_array {
         let mut writer = BufWriter::new(File::create(path).unwrap());
         writeln!(
             writer,
-            "use non_contiguously_indexed_array::NciArray;\n\npub const GENERATED_{}: NciArray<{}, {}> = NciArray {};",
+            "use non_contiguously_indexed_array::{};\n\npub const GENERATED_{}: {}<{}, {}> = {} {};",
+            stringify!($array_ty),
             $id,
+            stringify!($array_ty),
             $ty.0,
             $ty.1,
-            builder.build($conf),
+            stringify!($array_ty),
+            builder.$build_fn($conf),
         )
         .unwrap();
     };
@@ -143,6 +152,583 @@ fn array_builder_test_5() {
     }
 }
 
+#[test]
+fn array_builder_test_char() {
+    // Every `char` from U+D700 to U+E0FF, which is contiguous, as it skips the surrogate code points
+    let entries = || {
+        ('\u{D700}'..='\u{E0FF}')
+            .chain(['a', 'c'])
+            .map(|c| (c, u32::from(c)))
+    };
+    build_test_array!(
+        13,
+        entries(),
+        ("char", "u32"),
+        &DEFAUTLT_BUILD_CONFIGURATION
+    );
+    let array = generated::test_generated_13::GENERATED_13;
+    assert_eq!(array.segments_idx_begin, ['a', 'c', '\u{D700}']);
+    assert_eq!(array.len(), 0x200 + 2);
+    let mut expected: Vec<_> = entries().collect();
+    expected.sort_unstable();
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(expected)
+    );
+}
+
+#[test]
+fn array_builder_test_ip() {
+    // The CIDR blocks 10.0.0.0/24 and 10.0.2.128/25, plus a single address
+    let entries = || {
+        (0x0A00_0000..=0x0A00_00FF)
+            .chain(0x0A00_0280..=0x0A00_02FF)
+            .chain([0xC0A8_0001])
+            .map(|bits: u32| (Ipv4Addr::from_bits(bits), bits.to_be_bytes()[3]))
+    };
+    build_test_array!(
+        14,
+        entries(),
+        ("::core::net::Ipv4Addr", "u8"),
+        &DEFAUTLT_BUILD_CONFIGURATION
+    );
+    let array = generated::test_generated_14::GENERATED_14;
+    assert_eq!(
+        array.segments_idx_begin,
+        [
+            Ipv4Addr::new(10, 0, 0, 0),
+            Ipv4Addr::new(10, 0, 2, 128),
+            Ipv4Addr::new(192, 168, 0, 1)
+        ]
+    );
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    assert_eq!(array.get(Ipv4Addr::new(10, 0, 1, 0)), None);
+}
+
+#[test]
+fn array_builder_test_non_zero() {
+    let entries = || {
+        (1..=100)
+            .chain(1000..=1010)
+            .map(|i: u16| (NonZeroU16::new(i).unwrap(), i))
+    };
+    build_test_array!(
+        15,
+        entries(),
+        ("::core::num::NonZeroU16", "u16"),
+        &DEFAUTLT_BUILD_CONFIGURATION
+    );
+    let array = generated::test_generated_15::GENERATED_15;
+    assert_eq!(array.len(), 111);
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+}
+
+#[test]
+fn array_builder_test_tuple() {
+    // A sparse 2D table, whose rows 1 to 3 are full, so they form a single segment
+    let entries = || {
+        [0u16, 1, 2, 3, 7].into_iter().flat_map(|row| {
+            let columns = if (1..=3).contains(&row) {
+                0..=u8::MAX
+            } else {
+                10..=12
+            };
+            columns.map(move |column| ((row, column), u32::from(row) * 1000 + u32::from(column)))
+        })
+    };
+    build_test_array!(
+        16,
+        entries(),
+        ("(u16, u8)", "u32"),
+        &DEFAUTLT_BUILD_CONFIGURATION
+    );
+    let array = generated::test_generated_16::GENERATED_16;
+    assert_eq!(array.segments_idx_begin, [(0, 10), (1, 0), (7, 10)]);
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    assert_eq!(array.get((2, 100)), Some(&2100));
+    assert_eq!(array.get((4, 0)), None);
+}
+
+#[test]
+fn array_builder_test_tuple_rust_expr() {
+    // Elements that are not written as plain literals have to be written as Rust expressions within the tuple
+    let entries = || {
+        [Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(192, 168, 0, 1)]
+            .into_iter()
+            .flat_map(|address| {
+                (1..=3)
+                    .chain([80, 443])
+                    .map(move |port: u16| ((address, NonZeroU16::new(port).unwrap()), port))
+            })
+    };
+    build_test_array!(
+        21,
+        entries(),
+        ("(::core::net::Ipv4Addr, ::core::num::NonZeroU16)", "u16"),
+        &DEFAUTLT_BUILD_CONFIGURATION
+    );
+    let array = generated::test_generated_21::GENERATED_21;
+    assert_eq!(array.segments_idx_begin.len(), 6);
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    assert_eq!(
+        array.get((Ipv4Addr::new(10, 0, 0, 1), NonZeroU16::new(4).unwrap())),
+        None
+    );
+}
+
+#[test]
+fn set_builder_test() {
+    let indices = || (0..1000u32).filter(|i| i % 100 < 10 || i % 7 == 0);
+    let mut builder = NciSetBuilder::new();
+    // In descending order and with duplicates, which the builder has to handle
+    for index in indices().rev().chain(indices()) {
+        builder.insert(index);
+    }
+    let path = path::absolute("./tests/generated")
+        .unwrap()
+        .join("test_generated_17.rs");
+    let mut writer = BufWriter::new(File::create(path).unwrap());
+    writeln!(
+        writer,
+        "use non_contiguously_indexed_array::NciSet;\n\npub const GENERATED_17: NciSet<u32> = NciSet {};",
+        builder.build(&DEFAUTLT_BUILD_CONFIGURATION),
+    )
+    .unwrap();
+
+    let set = generated::test_generated_17::GENERATED_17;
+    assert!(set.iter().eq(indices()));
+    assert_eq!(set.len(), indices().count());
+    assert_eq!(set.ranges().next(), Some(0..=9));
+    for index in 0..1100 {
+        assert_eq!(set.contains(index), indices().any(|i| i == index));
+    }
+}
+
+#[test]
+fn range_map_builder_test() {
+    let build_config = BuildConfiguration {
+        output_format: OutputFormat::RustCodegen,
+        value_formatting: ValueFormatting::Debug,
+    };
+    let mut builder = NciRangeMapBuilder::new();
+    builder.entry_range(0x0370..=0x03FF, "Greek and Coptic");
+    builder.entry_range(0x0000..=0x007F, "Basic Latin");
+    builder.entry(0x00D7, "Multiplication Sign");
+    builder.entry_range(0x0080..=0x00D6, "Latin-1 Supplement");
+    let path = path::absolute("./tests/generated")
+        .unwrap()
+        .join("test_generated_18.rs");
+    let mut writer = BufWriter::new(File::create(path).unwrap());
+    writeln!(
+        writer,
+        "use non_contiguously_indexed_array::NciRangeMap;\n\npub const GENERATED_18: NciRangeMap<u32, &str> = NciRangeMap {};",
+        builder.build(&build_config),
+    )
+    .unwrap();
+
+    let map = generated::test_generated_18::GENERATED_18;
+    assert!(
+        map.ranges()
+            .eq([0x00..=0x7F, 0x80..=0xD6, 0xD7..=0xD7, 0x370..=0x3FF])
+    );
+    assert_eq!(map.get(0x41), Some(&"Basic Latin"));
+    assert_eq!(map.get(0xD7), Some(&"Multiplication Sign"));
+    assert_eq!(map.get(0xD8), None);
+    assert_eq!(map.range_of(0x3A9), Some(0x370..=0x3FF));
+}
+
+#[test]
+#[should_panic]
+fn range_map_builder_test_panic_on_overlap() {
+    let mut builder = NciRangeMapBuilder::new();
+    builder.entry_range(0..=10, 'a');
+    builder.entry_range(20..=30, 'b');
+    builder.entry_range(10..=15, 'c');
+    assert!(builder.build(&DEFAUTLT_BUILD_CONFIGURATION).is_empty()); // Assertion fails, but should never be executed
+}
+
+#[test]
+#[should_panic]
+fn range_map_builder_test_panic_on_empty_range() {
+    let mut builder = NciRangeMapBuilder::<u8, u8>::new();
+    #[allow(clippy::reversed_empty_ranges)]
+    builder.entry_range(10..=5, 0);
+}
+
+#[test]
+fn array_builder_test_multi() {
+    const ALIASES: [&str; 4] = ["a", "b", "c", "d"];
+    // Every index from 0 to 99 except the multiples of 7 has `i % 4` values,
+    // so the indices that are multiples of 4 have none and are missing as well
+    let entries = || {
+        (0..100u16).filter(|i| i % 7 != 0).flat_map(|i| {
+            ALIASES[..usize::from(i % 4)]
+                .iter()
+                .map(move |alias| (i, *alias))
+        })
+    };
+    let build_config = BuildConfiguration {
+        output_format: OutputFormat::RustCodegen,
+        value_formatting: ValueFormatting::Debug,
+    };
+    let mut builder = NciArrayBuilder::new();
+    for (index, value) in entries() {
+        builder.entry(index, value);
+    }
+    assert_eq!(builder.multi_offset_type(), "u8");
+    // In reverse order of the indices, to verify that the values of each index keep their order
+    build_test_array!(
+        19,
+        (0..100u16)
+            .rev()
+            .flat_map(|index| entries().filter(move |(i, _)| *i == index)),
+        ("u16", "&str, u8"),
+        &build_config,
+        NciMultiArray,
+        build_multi
+    );
+    let array = generated::test_generated_19::GENERATED_19;
+    assert_eq!(
+        array.len(),
+        (0..100).filter(|i| i % 7 != 0 && i % 4 != 0).count()
+    );
+    assert_eq!(array.segments_idx_begin[..3], [1, 5, 9]);
+    assert_eq!(array.get(7), None);
+    assert_eq!(array.get(8), None);
+    assert_eq!(array.get(11), Some(&["a", "b", "c"][..]));
+    assert!(
+        array
+            .entries()
+            .flat_map(|(index, values)| values.iter().map(move |value| (index, *value)))
+            .eq(entries())
+    );
+}
+
+#[test]
+fn array_builder_test_str() {
+    const NAMES: [&str; 6] = ["", "alpha", "\"beta\"", "gamma\\delta", "ε\n", "ζήτα"];
+    let entries = || {
+        (0..1000u32)
+            .filter(|i| i % 13 != 0)
+            .map(|i| (i, NAMES[(i % 6) as usize]))
+    };
+    let mut builder = NciArrayBuilder::new();
+    for (index, value) in entries() {
+        builder.entry(index, value);
+    }
+    assert_eq!(
+        builder.str_offset_type(&DEFAUTLT_BUILD_CONFIGURATION),
+        "u16"
+    );
+    build_test_array!(
+        20,
+        entries(),
+        ("u32", "u16"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciStrArray,
+        build_str
+    );
+    let array = generated::test_generated_20::GENERATED_20;
+    assert!(array.entries().eq(entries()));
+    assert_eq!(array.get(13), None);
+    assert_eq!(array.get(14), Some("\"beta\""));
+}
+
+#[test]
+fn array_builder_test_eytzinger() {
+    let entries = || (0..300u16).filter(|i| i % 3 != 2).map(|i| (i, i / 3));
+    build_test_array!(
+        6,
+        entries(),
+        ("u16", "u16"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciEytzingerArray,
+        build_eytzinger
+    );
+    let array = generated::test_generated_6::GENERATED_6;
+    assert_eq!(array.segments_idx_begin.len(), 100);
+    assert!(
+        array
+            .values()
+            .copied()
+            .eq(entries().map(|(_, value)| value))
+    );
+    for i in 0..=310 {
+        let expected = (i < 300 && i % 3 != 2).then_some(i / 3);
+        assert_eq!(array.get(i).copied(), expected, "{i}");
+    }
+}
+
+#[test]
+fn array_builder_test_bucketed() {
+    // Segments of one to three entries, with increasingly large gaps between them
+    let entries = || {
+        (0..500u32).flat_map(|segment| {
+            (0..segment % 3 + 1)
+                .map(move |offset| (segment * segment * 13 + offset, segment + offset))
+        })
+    };
+    build_test_array!(
+        7,
+        entries(),
+        ("u32", "u32"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciBucketArray,
+        build_bucketed
+    );
+    let array = generated::test_generated_7::GENERATED_7;
+    assert_eq!(array.segments_idx_begin.len(), 500);
+    assert!(array.bucket_segments.len() <= 500);
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    let expected: std::collections::BTreeMap<_, _> = entries().collect();
+    for i in (0..3_300_000)
+        .step_by(7)
+        .chain(entries().flat_map(|(i, _)| [i.saturating_sub(1), i, i + 1]))
+    {
+        assert_eq!(array.get(i), expected.get(&i), "{i}");
+    }
+}
+
+#[test]
+fn array_builder_test_bitmap() {
+    // Every tenth index is missing, so there would be a segment for every nine entries
+    let entries = || {
+        (-3000..3000i16)
+            .filter(|i| i.rem_euclid(10) != 7)
+            .map(|i| (i, i / 2))
+    };
+    build_test_array!(
+        8,
+        entries(),
+        ("i16", "i16"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciBitmapArray,
+        build_bitmap
+    );
+    let array = generated::test_generated_8::GENERATED_8;
+    assert_eq!(array.idx_begin, Some(-3000));
+    assert_eq!(array.bitmap.len(), 6000usize.div_ceil(64));
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    for i in i16::MIN..=i16::MAX {
+        let expected = ((-3000..3000).contains(&i) && i.rem_euclid(10) != 7).then_some(i / 2);
+        assert_eq!(array.get(i).copied(), expected, "{i}");
+    }
+}
+
+#[test]
+fn array_builder_test_run_length() {
+    // Runs of 64 equal values, interrupted by gaps
+    let entries = || {
+        (0..1000u32)
+            .filter(|i| i % 250 < 200)
+            .map(|i| (i, (i / 64) as u8))
+    };
+    build_test_array!(
+        9,
+        entries(),
+        ("u32", "u8"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciRunArray,
+        build_run_length
+    );
+    let array = generated::test_generated_9::GENERATED_9;
+    assert_eq!(array.len(), 800);
+    assert_eq!(array.run_values().len(), 17);
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    for i in 0..1100 {
+        let expected = (i < 1000 && i % 250 < 200).then_some((i / 64) as u8);
+        assert_eq!(array.get(i).copied(), expected, "{i}");
+    }
+}
+
+#[test]
+fn array_builder_test_interned() {
+    const NAMES: [&str; 5] = ["alpha", "beta", "gamma", "delta", "epsilon"];
+    let entries = || {
+        (0..2000u16)
+            .filter(|i| i % 7 != 0)
+            .map(|i| (i, NAMES[usize::from(i % 5)]))
+    };
+    let build_config = BuildConfiguration {
+        output_format: OutputFormat::RustCodegen,
+        value_formatting: ValueFormatting::Debug,
+    };
+    let mut builder = NciArrayBuilder::new();
+    for (index, value) in entries() {
+        builder.entry(index, value);
+    }
+    assert_eq!(builder.interned_id_type(&build_config), "u8");
+    // The id type is part of the type of the generated constant
+    build_test_array!(
+        10,
+        entries(),
+        ("u16", "&str, u8"),
+        &build_config,
+        NciInternedArray,
+        build_interned
+    );
+    let array = generated::test_generated_10::GENERATED_10;
+    // In order of their first occurrence, which is index 1
+    assert_eq!(
+        array.unique_values,
+        ["beta", "gamma", "delta", "epsilon", "alpha"]
+    );
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    for i in 0..2100 {
+        let expected = (i < 2000 && i % 7 != 0).then_some(NAMES[usize::from(i % 5)]);
+        assert_eq!(array.get(i).copied(), expected, "{i}");
+    }
+}
+
+#[test]
+fn array_builder_test_interned_id_type() {
+    let mut builder = NciArrayBuilder::new();
+    for i in 0..256u32 {
+        builder.entry(i, i % 256);
+    }
+    assert_eq!(
+        builder.interned_id_type(&DEFAUTLT_BUILD_CONFIGURATION),
+        "u8"
+    );
+    builder.entry(256, 256);
+    assert_eq!(
+        builder.interned_id_type(&DEFAUTLT_BUILD_CONFIGURATION),
+        "u16"
+    );
+    builder.entry(257, 0);
+    assert_eq!(
+        builder.interned_id_type(&DEFAUTLT_BUILD_CONFIGURATION),
+        "u16"
+    );
+}
+
+#[test]
+fn array_builder_test_mem_idx_type() {
+    let entries = || (0..1000u32).map(|i| (i * 2, i));
+    let mut builder = NciArrayBuilder::new();
+    for (index, value) in entries() {
+        builder.entry(index, value);
+    }
+    assert_eq!(builder.mem_idx_type(), "u16");
+    // The memory index type is part of the type of the generated constant
+    build_test_array!(
+        11,
+        entries(),
+        ("u32", "u32, u16"),
+        &DEFAUTLT_BUILD_CONFIGURATION
+    );
+    let array = generated::test_generated_11::GENERATED_11;
+    assert_eq!(array.segments_mem_idx_begin.last(), Some(&999));
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+
+    let mut builder = NciArrayBuilder::new();
+    assert_eq!(builder.mem_idx_type(), "u8");
+    for i in 0..=255u32 {
+        builder.entry(i * 2, i);
+    }
+    assert_eq!(builder.mem_idx_type(), "u8");
+    builder.entry(1000, 0);
+    assert_eq!(builder.mem_idx_type(), "u16");
+    builder.entry(1001, 0);
+    assert_eq!(builder.mem_idx_type(), "u16");
+}
+
+#[test]
+fn array_builder_test_segment_len() {
+    // Many short segments, followed by a segment that is too long for a `u8` length
+    let entries = || {
+        (0..300u32)
+            .filter(|i| i % 4 != 3)
+            .chain(1000..1600)
+            .map(|i| (i, i / 2))
+    };
+    build_test_array!(
+        12,
+        entries(),
+        ("u32", "u32"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciSegmentLenArray,
+        build_segment_len
+    );
+    let array = generated::test_generated_12::GENERATED_12;
+    assert_eq!(array.segments_idx_begin.len(), 75 + 3);
+    assert_eq!(&array.segments_len[75..], [255, 255, 90]);
+    assert_eq!(array.mem_idx_checkpoints.len(), 78usize.div_ceil(16));
+    assert!(
+        array
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq(entries())
+    );
+    for i in 0..1700 {
+        let expected = ((i < 300 && i % 4 != 3) || (1000..1600).contains(&i)).then_some(i / 2);
+        assert_eq!(array.get(i).copied(), expected, "{i}");
+    }
+}
+
+#[test]
+fn array_builder_test_bitmap_empty() {
+    build_test_array!(
+        22,
+        std::iter::empty::<(u8, u8)>(),
+        ("u8", "u8"),
+        &DEFAUTLT_BUILD_CONFIGURATION,
+        NciBitmapArray,
+        build_bitmap
+    );
+    let array = generated::test_generated_22::GENERATED_22;
+    assert!(array.is_empty());
+    assert_eq!(array.idx_begin, None);
+    assert_eq!(array.get(0), None);
+}
+
 #[test]
 #[should_panic]
 fn array_builder_test_panic_on_duplicate() {
@@ -7,3 +7,37 @@ pub mod test_generated_3;
 pub mod test_generated_4;
 
 pub mod test_generated_5;
+
+pub mod test_generated_6;
+
+pub mod test_generated_7;
+
+pub mod test_generated_8;
+
+pub mod test_generated_9;
+
+pub mod test_generated_10;
+
+pub mod test_generated_11;
+
+pub mod test_generated_12;
+
+pub mod test_generated_13;
+
+pub mod test_generated_14;
+
+pub mod test_generated_15;
+
+pub mod test_generated_16;
+
+pub mod test_generated_17;
+
+pub mod test_generated_18;
+
+pub mod test_generated_19;
+
+pub mod test_generated_20;
+
+pub mod test_generated_21;
+
+pub mod test_generated_22;
@@ -0,0 +1,2303 @@
+use non_contiguously_indexed_array::NciInternedArray;
+
+pub const GENERATED_10: NciInternedArray<u16, &str, u8> = NciInternedArray {
+	segments_idx_begin: &[
+		1,
+		8,
+		15,
+		22,
+		29,
+		36,
+		43,
+		50,
+		57,
+		64,
+		71,
+		78,
+		85,
+		92,
+		99,
+		106,
+		113,
+		120,
+		127,
+		134,
+		141,
+		148,
+		155,
+		162,
+		169,
+		176,
+		183,
+		190,
+		197,
+		204,
+		211,
+		218,
+		225,
+		232,
+		239,
+		246,
+		253,
+		260,
+		267,
+		274,
+		281,
+		288,
+		295,
+		302,
+		309,
+		316,
+		323,
+		330,
+		337,
+		344,
+		351,
+		358,
+		365,
+		372,
+		379,
+		386,
+		393,
+		400,
+		407,
+		414,
+		421,
+		428,
+		435,
+		442,
+		449,
+		456,
+		463,
+		470,
+		477,
+		484,
+		491,
+		498,
+		505,
+		512,
+		519,
+		526,
+		533,
+		540,
+		547,
+		554,
+		561,
+		568,
+		575,
+		582,
+		589,
+		596,
+		603,
+		610,
+		617,
+		624,
+		631,
+		638,
+		645,
+		652,
+		659,
+		666,
+		673,
+		680,
+		687,
+		694,
+		701,
+		708,
+		715,
+		722,
+		729,
+		736,
+		743,
+		750,
+		757,
+		764,
+		771,
+		778,
+		785,
+		792,
+		799,
+		806,
+		813,
+		820,
+		827,
+		834,
+		841,
+		848,
+		855,
+		862,
+		869,
+		876,
+		883,
+		890,
+		897,
+		904,
+		911,
+		918,
+		925,
+		932,
+		939,
+		946,
+		953,
+		960,
+		967,
+		974,
+		981,
+		988,
+		995,
+		1002,
+		1009,
+		1016,
+		1023,
+		1030,
+		1037,
+		1044,
+		1051,
+		1058,
+		1065,
+		1072,
+		1079,
+		1086,
+		1093,
+		1100,
+		1107,
+		1114,
+		1121,
+		1128,
+		1135,
+		1142,
+		1149,
+		1156,
+		1163,
+		1170,
+		1177,
+		1184,
+		1191,
+		1198,
+		1205,
+		1212,
+		1219,
+		1226,
+		1233,
+		1240,
+		1247,
+		1254,
+		1261,
+		1268,
+		1275,
+		1282,
+		1289,
+		1296,
+		1303,
+		1310,
+		1317,
+		1324,
+		1331,
+		1338,
+		1345,
+		1352,
+		1359,
+		1366,
+		1373,
+		1380,
+		1387,
+		1394,
+		1401,
+		1408,
+		1415,
+		1422,
+		1429,
+		1436,
+		1443,
+		1450,
+		1457,
+		1464,
+		1471,
+		1478,
+		1485,
+		1492,
+		1499,
+		1506,
+		1513,
+		1520,
+		1527,
+		1534,
+		1541,
+		1548,
+		1555,
+		1562,
+		1569,
+		1576,
+		1583,
+		1590,
+		1597,
+		1604,
+		1611,
+		1618,
+		1625,
+		1632,
+		1639,
+		1646,
+		1653,
+		1660,
+		1667,
+		1674,
+		1681,
+		1688,
+		1695,
+		1702,
+		1709,
+		1716,
+		1723,
+		1730,
+		1737,
+		1744,
+		1751,
+		1758,
+		1765,
+		1772,
+		1779,
+		1786,
+		1793,
+		1800,
+		1807,
+		1814,
+		1821,
+		1828,
+		1835,
+		1842,
+		1849,
+		1856,
+		1863,
+		1870,
+		1877,
+		1884,
+		1891,
+		1898,
+		1905,
+		1912,
+		1919,
+		1926,
+		1933,
+		1940,
+		1947,
+		1954,
+		1961,
+		1968,
+		1975,
+		1982,
+		1989,
+		1996,
+	],
+	segments_mem_idx_begin: &[
+		0,
+		6,
+		12,
+		18,
+		24,
+		30,
+		36,
+		42,
+		48,
+		54,
+		60,
+		66,
+		72,
+		78,
+		84,
+		90,
+		96,
+		102,
+		108,
+		114,
+		120,
+		126,
+		132,
+		138,
+		144,
+		150,
+		156,
+		162,
+		168,
+		174,
+		180,
+		186,
+		192,
+		198,
+		204,
+		210,
+		216,
+		222,
+		228,
+		234,
+		240,
+		246,
+		252,
+		258,
+		264,
+		270,
+		276,
+		282,
+		288,
+		294,
+		300,
+		306,
+		312,
+		318,
+		324,
+		330,
+		336,
+		342,
+		348,
+		354,
+		360,
+		366,
+		372,
+		378,
+		384,
+		390,
+		396,
+		402,
+		408,
+		414,
+		420,
+		426,
+		432,
+		438,
+		444,
+		450,
+		456,
+		462,
+		468,
+		474,
+		480,
+		486,
+		492,
+		498,
+		504,
+		510,
+		516,
+		522,
+		528,
+		534,
+		540,
+		546,
+		552,
+		558,
+		564,
+		570,
+		576,
+		582,
+		588,
+		594,
+		600,
+		606,
+		612,
+		618,
+		624,
+		630,
+		636,
+		642,
+		648,
+		654,
+		660,
+		666,
+		672,
+		678,
+		684,
+		690,
+		696,
+		702,
+		708,
+		714,
+		720,
+		726,
+		732,
+		738,
+		744,
+		750,
+		756,
+		762,
+		768,
+		774,
+		780,
+		786,
+		792,
+		798,
+		804,
+		810,
+		816,
+		822,
+		828,
+		834,
+		840,
+		846,
+		852,
+		858,
+		864,
+		870,
+		876,
+		882,
+		888,
+		894,
+		900,
+		906,
+		912,
+		918,
+		924,
+		930,
+		936,
+		942,
+		948,
+		954,
+		960,
+		966,
+		972,
+		978,
+		984,
+		990,
+		996,
+		1002,
+		1008,
+		1014,
+		1020,
+		1026,
+		1032,
+		1038,
+		1044,
+		1050,
+		1056,
+		1062,
+		1068,
+		1074,
+		1080,
+		1086,
+		1092,
+		1098,
+		1104,
+		1110,
+		1116,
+		1122,
+		1128,
+		1134,
+		1140,
+		1146,
+		1152,
+		1158,
+		1164,
+		1170,
+		1176,
+		1182,
+		1188,
+		1194,
+		1200,
+		1206,
+		1212,
+		1218,
+		1224,
+		1230,
+		1236,
+		1242,
+		1248,
+		1254,
+		1260,
+		1266,
+		1272,
+		1278,
+		1284,
+		1290,
+		1296,
+		1302,
+		1308,
+		1314,
+		1320,
+		1326,
+		1332,
+		1338,
+		1344,
+		1350,
+		1356,
+		1362,
+		1368,
+		1374,
+		1380,
+		1386,
+		1392,
+		1398,
+		1404,
+		1410,
+		1416,
+		1422,
+		1428,
+		1434,
+		1440,
+		1446,
+		1452,
+		1458,
+		1464,
+		1470,
+		1476,
+		1482,
+		1488,
+		1494,
+		1500,
+		1506,
+		1512,
+		1518,
+		1524,
+		1530,
+		1536,
+		1542,
+		1548,
+		1554,
+		1560,
+		1566,
+		1572,
+		1578,
+		1584,
+		1590,
+		1596,
+		1602,
+		1608,
+		1614,
+		1620,
+		1626,
+		1632,
+		1638,
+		1644,
+		1650,
+		1656,
+		1662,
+		1668,
+		1674,
+		1680,
+		1686,
+		1692,
+		1698,
+		1704,
+		1710,
+	],
+	ids: &[
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+		4,
+		0,
+		2,
+		3,
+		4,
+		0,
+		1,
+		2,
+		4,
+		0,
+		1,
+		2,
+		3,
+		4,
+		1,
+		2,
+		3,
+		4,
+		0,
+		1,
+		3,
+		4,
+		0,
+		1,
+		2,
+		3,
+		0,
+		1,
+		2,
+		3,
+	],
+	unique_values: &[
+		"beta",
+		"gamma",
+		"delta",
+		"epsilon",
+		"alpha",
+	],
+};
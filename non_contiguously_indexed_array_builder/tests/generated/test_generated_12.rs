@@ -0,0 +1,998 @@
+use non_contiguously_indexed_array::NciSegmentLenArray;
+
+pub const GENERATED_12: NciSegmentLenArray<u32, u32> = NciSegmentLenArray {
+	segments_idx_begin: &[
+		0,
+		4,
+		8,
+		12,
+		16,
+		20,
+		24,
+		28,
+		32,
+		36,
+		40,
+		44,
+		48,
+		52,
+		56,
+		60,
+		64,
+		68,
+		72,
+		76,
+		80,
+		84,
+		88,
+		92,
+		96,
+		100,
+		104,
+		108,
+		112,
+		116,
+		120,
+		124,
+		128,
+		132,
+		136,
+		140,
+		144,
+		148,
+		152,
+		156,
+		160,
+		164,
+		168,
+		172,
+		176,
+		180,
+		184,
+		188,
+		192,
+		196,
+		200,
+		204,
+		208,
+		212,
+		216,
+		220,
+		224,
+		228,
+		232,
+		236,
+		240,
+		244,
+		248,
+		252,
+		256,
+		260,
+		264,
+		268,
+		272,
+		276,
+		280,
+		284,
+		288,
+		292,
+		296,
+		1000,
+		1255,
+		1510,
+	],
+	segments_len: &[
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		3,
+		255,
+		255,
+		90,
+	],
+	mem_idx_checkpoints: &[
+		0,
+		48,
+		96,
+		144,
+		192,
+	],
+	values: &[
+		0,
+		0,
+		1,
+		2,
+		2,
+		3,
+		4,
+		4,
+		5,
+		6,
+		6,
+		7,
+		8,
+		8,
+		9,
+		10,
+		10,
+		11,
+		12,
+		12,
+		13,
+		14,
+		14,
+		15,
+		16,
+		16,
+		17,
+		18,
+		18,
+		19,
+		20,
+		20,
+		21,
+		22,
+		22,
+		23,
+		24,
+		24,
+		25,
+		26,
+		26,
+		27,
+		28,
+		28,
+		29,
+		30,
+		30,
+		31,
+		32,
+		32,
+		33,
+		34,
+		34,
+		35,
+		36,
+		36,
+		37,
+		38,
+		38,
+		39,
+		40,
+		40,
+		41,
+		42,
+		42,
+		43,
+		44,
+		44,
+		45,
+		46,
+		46,
+		47,
+		48,
+		48,
+		49,
+		50,
+		50,
+		51,
+		52,
+		52,
+		53,
+		54,
+		54,
+		55,
+		56,
+		56,
+		57,
+		58,
+		58,
+		59,
+		60,
+		60,
+		61,
+		62,
+		62,
+		63,
+		64,
+		64,
+		65,
+		66,
+		66,
+		67,
+		68,
+		68,
+		69,
+		70,
+		70,
+		71,
+		72,
+		72,
+		73,
+		74,
+		74,
+		75,
+		76,
+		76,
+		77,
+		78,
+		78,
+		79,
+		80,
+		80,
+		81,
+		82,
+		82,
+		83,
+		84,
+		84,
+		85,
+		86,
+		86,
+		87,
+		88,
+		88,
+		89,
+		90,
+		90,
+		91,
+		92,
+		92,
+		93,
+		94,
+		94,
+		95,
+		96,
+		96,
+		97,
+		98,
+		98,
+		99,
+		100,
+		100,
+		101,
+		102,
+		102,
+		103,
+		104,
+		104,
+		105,
+		106,
+		106,
+		107,
+		108,
+		108,
+		109,
+		110,
+		110,
+		111,
+		112,
+		112,
+		113,
+		114,
+		114,
+		115,
+		116,
+		116,
+		117,
+		118,
+		118,
+		119,
+		120,
+		120,
+		121,
+		122,
+		122,
+		123,
+		124,
+		124,
+		125,
+		126,
+		126,
+		127,
+		128,
+		128,
+		129,
+		130,
+		130,
+		131,
+		132,
+		132,
+		133,
+		134,
+		134,
+		135,
+		136,
+		136,
+		137,
+		138,
+		138,
+		139,
+		140,
+		140,
+		141,
+		142,
+		142,
+		143,
+		144,
+		144,
+		145,
+		146,
+		146,
+		147,
+		148,
+		148,
+		149,
+		500,
+		500,
+		501,
+		501,
+		502,
+		502,
+		503,
+		503,
+		504,
+		504,
+		505,
+		505,
+		506,
+		506,
+		507,
+		507,
+		508,
+		508,
+		509,
+		509,
+		510,
+		510,
+		511,
+		511,
+		512,
+		512,
+		513,
+		513,
+		514,
+		514,
+		515,
+		515,
+		516,
+		516,
+		517,
+		517,
+		518,
+		518,
+		519,
+		519,
+		520,
+		520,
+		521,
+		521,
+		522,
+		522,
+		523,
+		523,
+		524,
+		524,
+		525,
+		525,
+		526,
+		526,
+		527,
+		527,
+		528,
+		528,
+		529,
+		529,
+		530,
+		530,
+		531,
+		531,
+		532,
+		532,
+		533,
+		533,
+		534,
+		534,
+		535,
+		535,
+		536,
+		536,
+		537,
+		537,
+		538,
+		538,
+		539,
+		539,
+		540,
+		540,
+		541,
+		541,
+		542,
+		542,
+		543,
+		543,
+		544,
+		544,
+		545,
+		545,
+		546,
+		546,
+		547,
+		547,
+		548,
+		548,
+		549,
+		549,
+		550,
+		550,
+		551,
+		551,
+		552,
+		552,
+		553,
+		553,
+		554,
+		554,
+		555,
+		555,
+		556,
+		556,
+		557,
+		557,
+		558,
+		558,
+		559,
+		559,
+		560,
+		560,
+		561,
+		561,
+		562,
+		562,
+		563,
+		563,
+		564,
+		564,
+		565,
+		565,
+		566,
+		566,
+		567,
+		567,
+		568,
+		568,
+		569,
+		569,
+		570,
+		570,
+		571,
+		571,
+		572,
+		572,
+		573,
+		573,
+		574,
+		574,
+		575,
+		575,
+		576,
+		576,
+		577,
+		577,
+		578,
+		578,
+		579,
+		579,
+		580,
+		580,
+		581,
+		581,
+		582,
+		582,
+		583,
+		583,
+		584,
+		584,
+		585,
+		585,
+		586,
+		586,
+		587,
+		587,
+		588,
+		588,
+		589,
+		589,
+		590,
+		590,
+		591,
+		591,
+		592,
+		592,
+		593,
+		593,
+		594,
+		594,
+		595,
+		595,
+		596,
+		596,
+		597,
+		597,
+		598,
+		598,
+		599,
+		599,
+		600,
+		600,
+		601,
+		601,
+		602,
+		602,
+		603,
+		603,
+		604,
+		604,
+		605,
+		605,
+		606,
+		606,
+		607,
+		607,
+		608,
+		608,
+		609,
+		609,
+		610,
+		610,
+		611,
+		611,
+		612,
+		612,
+		613,
+		613,
+		614,
+		614,
+		615,
+		615,
+		616,
+		616,
+		617,
+		617,
+		618,
+		618,
+		619,
+		619,
+		620,
+		620,
+		621,
+		621,
+		622,
+		622,
+		623,
+		623,
+		624,
+		624,
+		625,
+		625,
+		626,
+		626,
+		627,
+		627,
+		628,
+		628,
+		629,
+		629,
+		630,
+		630,
+		631,
+		631,
+		632,
+		632,
+		633,
+		633,
+		634,
+		634,
+		635,
+		635,
+		636,
+		636,
+		637,
+		637,
+		638,
+		638,
+		639,
+		639,
+		640,
+		640,
+		641,
+		641,
+		642,
+		642,
+		643,
+		643,
+		644,
+		644,
+		645,
+		645,
+		646,
+		646,
+		647,
+		647,
+		648,
+		648,
+		649,
+		649,
+		650,
+		650,
+		651,
+		651,
+		652,
+		652,
+		653,
+		653,
+		654,
+		654,
+		655,
+		655,
+		656,
+		656,
+		657,
+		657,
+		658,
+		658,
+		659,
+		659,
+		660,
+		660,
+		661,
+		661,
+		662,
+		662,
+		663,
+		663,
+		664,
+		664,
+		665,
+		665,
+		666,
+		666,
+		667,
+		667,
+		668,
+		668,
+		669,
+		669,
+		670,
+		670,
+		671,
+		671,
+		672,
+		672,
+		673,
+		673,
+		674,
+		674,
+		675,
+		675,
+		676,
+		676,
+		677,
+		677,
+		678,
+		678,
+		679,
+		679,
+		680,
+		680,
+		681,
+		681,
+		682,
+		682,
+		683,
+		683,
+		684,
+		684,
+		685,
+		685,
+		686,
+		686,
+		687,
+		687,
+		688,
+		688,
+		689,
+		689,
+		690,
+		690,
+		691,
+		691,
+		692,
+		692,
+		693,
+		693,
+		694,
+		694,
+		695,
+		695,
+		696,
+		696,
+		697,
+		697,
+		698,
+		698,
+		699,
+		699,
+		700,
+		700,
+		701,
+		701,
+		702,
+		702,
+		703,
+		703,
+		704,
+		704,
+		705,
+		705,
+		706,
+		706,
+		707,
+		707,
+		708,
+		708,
+		709,
+		709,
+		710,
+		710,
+		711,
+		711,
+		712,
+		712,
+		713,
+		713,
+		714,
+		714,
+		715,
+		715,
+		716,
+		716,
+		717,
+		717,
+		718,
+		718,
+		719,
+		719,
+		720,
+		720,
+		721,
+		721,
+		722,
+		722,
+		723,
+		723,
+		724,
+		724,
+		725,
+		725,
+		726,
+		726,
+		727,
+		727,
+		728,
+		728,
+		729,
+		729,
+		730,
+		730,
+		731,
+		731,
+		732,
+		732,
+		733,
+		733,
+		734,
+		734,
+		735,
+		735,
+		736,
+		736,
+		737,
+		737,
+		738,
+		738,
+		739,
+		739,
+		740,
+		740,
+		741,
+		741,
+		742,
+		742,
+		743,
+		743,
+		744,
+		744,
+		745,
+		745,
+		746,
+		746,
+		747,
+		747,
+		748,
+		748,
+		749,
+		749,
+		750,
+		750,
+		751,
+		751,
+		752,
+		752,
+		753,
+		753,
+		754,
+		754,
+		755,
+		755,
+		756,
+		756,
+		757,
+		757,
+		758,
+		758,
+		759,
+		759,
+		760,
+		760,
+		761,
+		761,
+		762,
+		762,
+		763,
+		763,
+		764,
+		764,
+		765,
+		765,
+		766,
+		766,
+		767,
+		767,
+		768,
+		768,
+		769,
+		769,
+		770,
+		770,
+		771,
+		771,
+		772,
+		772,
+		773,
+		773,
+		774,
+		774,
+		775,
+		775,
+		776,
+		776,
+		777,
+		777,
+		778,
+		778,
+		779,
+		779,
+		780,
+		780,
+		781,
+		781,
+		782,
+		782,
+		783,
+		783,
+		784,
+		784,
+		785,
+		785,
+		786,
+		786,
+		787,
+		787,
+		788,
+		788,
+		789,
+		789,
+		790,
+		790,
+		791,
+		791,
+		792,
+		792,
+		793,
+		793,
+		794,
+		794,
+		795,
+		795,
+		796,
+		796,
+		797,
+		797,
+		798,
+		798,
+		799,
+		799,
+	],
+};
@@ -0,0 +1,530 @@
+use non_contiguously_indexed_array::NciArray;
+
+pub const GENERATED_13: NciArray<char, u32> = NciArray {
+	segments_idx_begin: &[
+		'a',
+		'c',
+		'휀',
+	],
+	segments_mem_idx_begin: &[
+		0,
+		1,
+		2,
+	],
+	values: &[
+		97,
+		99,
+		55040,
+		55041,
+		55042,
+		55043,
+		55044,
+		55045,
+		55046,
+		55047,
+		55048,
+		55049,
+		55050,
+		55051,
+		55052,
+		55053,
+		55054,
+		55055,
+		55056,
+		55057,
+		55058,
+		55059,
+		55060,
+		55061,
+		55062,
+		55063,
+		55064,
+		55065,
+		55066,
+		55067,
+		55068,
+		55069,
+		55070,
+		55071,
+		55072,
+		55073,
+		55074,
+		55075,
+		55076,
+		55077,
+		55078,
+		55079,
+		55080,
+		55081,
+		55082,
+		55083,
+		55084,
+		55085,
+		55086,
+		55087,
+		55088,
+		55089,
+		55090,
+		55091,
+		55092,
+		55093,
+		55094,
+		55095,
+		55096,
+		55097,
+		55098,
+		55099,
+		55100,
+		55101,
+		55102,
+		55103,
+		55104,
+		55105,
+		55106,
+		55107,
+		55108,
+		55109,
+		55110,
+		55111,
+		55112,
+		55113,
+		55114,
+		55115,
+		55116,
+		55117,
+		55118,
+		55119,
+		55120,
+		55121,
+		55122,
+		55123,
+		55124,
+		55125,
+		55126,
+		55127,
+		55128,
+		55129,
+		55130,
+		55131,
+		55132,
+		55133,
+		55134,
+		55135,
+		55136,
+		55137,
+		55138,
+		55139,
+		55140,
+		55141,
+		55142,
+		55143,
+		55144,
+		55145,
+		55146,
+		55147,
+		55148,
+		55149,
+		55150,
+		55151,
+		55152,
+		55153,
+		55154,
+		55155,
+		55156,
+		55157,
+		55158,
+		55159,
+		55160,
+		55161,
+		55162,
+		55163,
+		55164,
+		55165,
+		55166,
+		55167,
+		55168,
+		55169,
+		55170,
+		55171,
+		55172,
+		55173,
+		55174,
+		55175,
+		55176,
+		55177,
+		55178,
+		55179,
+		55180,
+		55181,
+		55182,
+		55183,
+		55184,
+		55185,
+		55186,
+		55187,
+		55188,
+		55189,
+		55190,
+		55191,
+		55192,
+		55193,
+		55194,
+		55195,
+		55196,
+		55197,
+		55198,
+		55199,
+		55200,
+		55201,
+		55202,
+		55203,
+		55204,
+		55205,
+		55206,
+		55207,
+		55208,
+		55209,
+		55210,
+		55211,
+		55212,
+		55213,
+		55214,
+		55215,
+		55216,
+		55217,
+		55218,
+		55219,
+		55220,
+		55221,
+		55222,
+		55223,
+		55224,
+		55225,
+		55226,
+		55227,
+		55228,
+		55229,
+		55230,
+		55231,
+		55232,
+		55233,
+		55234,
+		55235,
+		55236,
+		55237,
+		55238,
+		55239,
+		55240,
+		55241,
+		55242,
+		55243,
+		55244,
+		55245,
+		55246,
+		55247,
+		55248,
+		55249,
+		55250,
+		55251,
+		55252,
+		55253,
+		55254,
+		55255,
+		55256,
+		55257,
+		55258,
+		55259,
+		55260,
+		55261,
+		55262,
+		55263,
+		55264,
+		55265,
+		55266,
+		55267,
+		55268,
+		55269,
+		55270,
+		55271,
+		55272,
+		55273,
+		55274,
+		55275,
+		55276,
+		55277,
+		55278,
+		55279,
+		55280,
+		55281,
+		55282,
+		55283,
+		55284,
+		55285,
+		55286,
+		55287,
+		55288,
+		55289,
+		55290,
+		55291,
+		55292,
+		55293,
+		55294,
+		55295,
+		57344,
+		57345,
+		57346,
+		57347,
+		57348,
+		57349,
+		57350,
+		57351,
+		57352,
+		57353,
+		57354,
+		57355,
+		57356,
+		57357,
+		57358,
+		57359,
+		57360,
+		57361,
+		57362,
+		57363,
+		57364,
+		57365,
+		57366,
+		57367,
+		57368,
+		57369,
+		57370,
+		57371,
+		57372,
+		57373,
+		57374,
+		57375,
+		57376,
+		57377,
+		57378,
+		57379,
+		57380,
+		57381,
+		57382,
+		57383,
+		57384,
+		57385,
+		57386,
+		57387,
+		57388,
+		57389,
+		57390,
+		57391,
+		57392,
+		57393,
+		57394,
+		57395,
+		57396,
+		57397,
+		57398,
+		57399,
+		57400,
+		57401,
+		57402,
+		57403,
+		57404,
+		57405,
+		57406,
+		57407,
+		57408,
+		57409,
+		57410,
+		57411,
+		57412,
+		57413,
+		57414,
+		57415,
+		57416,
+		57417,
+		57418,
+		57419,
+		57420,
+		57421,
+		57422,
+		57423,
+		57424,
+		57425,
+		57426,
+		57427,
+		57428,
+		57429,
+		57430,
+		57431,
+		57432,
+		57433,
+		57434,
+		57435,
+		57436,
+		57437,
+		57438,
+		57439,
+		57440,
+		57441,
+		57442,
+		57443,
+		57444,
+		57445,
+		57446,
+		57447,
+		57448,
+		57449,
+		57450,
+		57451,
+		57452,
+		57453,
+		57454,
+		57455,
+		57456,
+		57457,
+		57458,
+		57459,
+		57460,
+		57461,
+		57462,
+		57463,
+		57464,
+		57465,
+		57466,
+		57467,
+		57468,
+		57469,
+		57470,
+		57471,
+		57472,
+		57473,
+		57474,
+		57475,
+		57476,
+		57477,
+		57478,
+		57479,
+		57480,
+		57481,
+		57482,
+		57483,
+		57484,
+		57485,
+		57486,
+		57487,
+		57488,
+		57489,
+		57490,
+		57491,
+		57492,
+		57493,
+		57494,
+		57495,
+		57496,
+		57497,
+		57498,
+		57499,
+		57500,
+		57501,
+		57502,
+		57503,
+		57504,
+		57505,
+		57506,
+		57507,
+		57508,
+		57509,
+		57510,
+		57511,
+		57512,
+		57513,
+		57514,
+		57515,
+		57516,
+		57517,
+		57518,
+		57519,
+		57520,
+		57521,
+		57522,
+		57523,
+		57524,
+		57525,
+		57526,
+		57527,
+		57528,
+		57529,
+		57530,
+		57531,
+		57532,
+		57533,
+		57534,
+		57535,
+		57536,
+		57537,
+		57538,
+		57539,
+		57540,
+		57541,
+		57542,
+		57543,
+		57544,
+		57545,
+		57546,
+		57547,
+		57548,
+		57549,
+		57550,
+		57551,
+		57552,
+		57553,
+		57554,
+		57555,
+		57556,
+		57557,
+		57558,
+		57559,
+		57560,
+		57561,
+		57562,
+		57563,
+		57564,
+		57565,
+		57566,
+		57567,
+		57568,
+		57569,
+		57570,
+		57571,
+		57572,
+		57573,
+		57574,
+		57575,
+		57576,
+		57577,
+		57578,
+		57579,
+		57580,
+		57581,
+		57582,
+		57583,
+		57584,
+		57585,
+		57586,
+		57587,
+		57588,
+		57589,
+		57590,
+		57591,
+		57592,
+		57593,
+		57594,
+		57595,
+		57596,
+		57597,
+		57598,
+		57599,
+	],
+};
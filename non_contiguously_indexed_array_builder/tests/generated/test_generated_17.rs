@@ -0,0 +1,280 @@
+use non_contiguously_indexed_array::NciSet;
+
+pub const GENERATED_17: NciSet<u32> = NciSet {
+	segments_idx_begin: &[
+		0,
+		14,
+		21,
+		28,
+		35,
+		42,
+		49,
+		56,
+		63,
+		70,
+		77,
+		84,
+		91,
+		98,
+		100,
+		112,
+		119,
+		126,
+		133,
+		140,
+		147,
+		154,
+		161,
+		168,
+		175,
+		182,
+		189,
+		196,
+		200,
+		217,
+		224,
+		231,
+		238,
+		245,
+		252,
+		259,
+		266,
+		273,
+		280,
+		287,
+		294,
+		300,
+		315,
+		322,
+		329,
+		336,
+		343,
+		350,
+		357,
+		364,
+		371,
+		378,
+		385,
+		392,
+		399,
+		413,
+		420,
+		427,
+		434,
+		441,
+		448,
+		455,
+		462,
+		469,
+		476,
+		483,
+		490,
+		497,
+		500,
+		511,
+		518,
+		525,
+		532,
+		539,
+		546,
+		553,
+		560,
+		567,
+		574,
+		581,
+		588,
+		595,
+		600,
+		616,
+		623,
+		630,
+		637,
+		644,
+		651,
+		658,
+		665,
+		672,
+		679,
+		686,
+		693,
+		700,
+		714,
+		721,
+		728,
+		735,
+		742,
+		749,
+		756,
+		763,
+		770,
+		777,
+		784,
+		791,
+		798,
+		800,
+		812,
+		819,
+		826,
+		833,
+		840,
+		847,
+		854,
+		861,
+		868,
+		875,
+		882,
+		889,
+		896,
+		900,
+		917,
+		924,
+		931,
+		938,
+		945,
+		952,
+		959,
+		966,
+		973,
+		980,
+		987,
+		994,
+	],
+	segments_len: &[
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		11,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		11,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		10,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		11,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+		1,
+	],
+};
@@ -0,0 +1,22 @@
+use non_contiguously_indexed_array::NciRangeMap;
+
+pub const GENERATED_18: NciRangeMap<u32, &str> = NciRangeMap {
+	ranges_idx_begin: &[
+		0,
+		128,
+		215,
+		880,
+	],
+	ranges_idx_end: &[
+		127,
+		214,
+		215,
+		1023,
+	],
+	values: &[
+		"Basic Latin",
+		"Latin-1 Supplement",
+		"Multiplication Sign",
+		"Greek and Coptic",
+	],
+};
@@ -0,0 +1,261 @@
+use non_contiguously_indexed_array::NciMultiArray;
+
+pub const GENERATED_19: NciMultiArray<u16, &str, u8> = NciMultiArray {
+	segments_idx_begin: &[
+		1,
+		5,
+		9,
+		13,
+		15,
+		17,
+		22,
+		25,
+		29,
+		33,
+		37,
+		41,
+		43,
+		45,
+		50,
+		53,
+		57,
+		61,
+		65,
+		69,
+		71,
+		73,
+		78,
+		81,
+		85,
+		89,
+		93,
+		97,
+		99,
+	],
+	segments_mem_idx_begin: &[
+		0,
+		3,
+		5,
+		8,
+		9,
+		10,
+		13,
+		15,
+		18,
+		21,
+		23,
+		26,
+		27,
+		28,
+		31,
+		33,
+		36,
+		39,
+		41,
+		44,
+		45,
+		46,
+		49,
+		51,
+		54,
+		57,
+		59,
+		62,
+		63,
+	],
+	values_begin: &[
+		0,
+		1,
+		3,
+		6,
+		7,
+		9,
+		10,
+		12,
+		15,
+		16,
+		19,
+		20,
+		22,
+		25,
+		27,
+		30,
+		31,
+		33,
+		36,
+		37,
+		39,
+		42,
+		43,
+		45,
+		46,
+		48,
+		51,
+		52,
+		55,
+		56,
+		58,
+		61,
+		63,
+		66,
+		67,
+		69,
+		72,
+		73,
+		75,
+		78,
+		79,
+		81,
+		82,
+		84,
+		87,
+		88,
+		91,
+		92,
+		94,
+		97,
+		99,
+		102,
+		103,
+		105,
+		108,
+		109,
+		111,
+		114,
+		115,
+		117,
+		118,
+		120,
+		123,
+		124,
+	],
+	values: &[
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"a",
+		"a",
+		"b",
+		"a",
+		"b",
+		"c",
+		"a",
+		"a",
+		"b",
+		"c",
+	],
+};
@@ -0,0 +1,1088 @@
+use non_contiguously_indexed_array::NciStrArray;
+
+pub const GENERATED_20: NciStrArray<u32, u16> = NciStrArray {
+	segments_idx_begin: &[
+		1,
+		14,
+		27,
+		40,
+		53,
+		66,
+		79,
+		92,
+		105,
+		118,
+		131,
+		144,
+		157,
+		170,
+		183,
+		196,
+		209,
+		222,
+		235,
+		248,
+		261,
+		274,
+		287,
+		300,
+		313,
+		326,
+		339,
+		352,
+		365,
+		378,
+		391,
+		404,
+		417,
+		430,
+		443,
+		456,
+		469,
+		482,
+		495,
+		508,
+		521,
+		534,
+		547,
+		560,
+		573,
+		586,
+		599,
+		612,
+		625,
+		638,
+		651,
+		664,
+		677,
+		690,
+		703,
+		716,
+		729,
+		742,
+		755,
+		768,
+		781,
+		794,
+		807,
+		820,
+		833,
+		846,
+		859,
+		872,
+		885,
+		898,
+		911,
+		924,
+		937,
+		950,
+		963,
+		976,
+		989,
+	],
+	segments_mem_idx_begin: &[
+		0,
+		12,
+		24,
+		36,
+		48,
+		60,
+		72,
+		84,
+		96,
+		108,
+		120,
+		132,
+		144,
+		156,
+		168,
+		180,
+		192,
+		204,
+		216,
+		228,
+		240,
+		252,
+		264,
+		276,
+		288,
+		300,
+		312,
+		324,
+		336,
+		348,
+		360,
+		372,
+		384,
+		396,
+		408,
+		420,
+		432,
+		444,
+		456,
+		468,
+		480,
+		492,
+		504,
+		516,
+		528,
+		540,
+		552,
+		564,
+		576,
+		588,
+		600,
+		612,
+		624,
+		636,
+		648,
+		660,
+		672,
+		684,
+		696,
+		708,
+		720,
+		732,
+		744,
+		756,
+		768,
+		780,
+		792,
+		804,
+		816,
+		828,
+		840,
+		852,
+		864,
+		876,
+		888,
+		900,
+		912,
+	],
+	values_begin: &[
+		0,
+		5,
+		11,
+		22,
+		25,
+		33,
+		33,
+		38,
+		44,
+		55,
+		58,
+		66,
+		66,
+		72,
+		83,
+		86,
+		94,
+		94,
+		99,
+		105,
+		116,
+		119,
+		127,
+		127,
+		132,
+		143,
+		146,
+		154,
+		154,
+		159,
+		165,
+		176,
+		179,
+		187,
+		187,
+		192,
+		198,
+		201,
+		209,
+		209,
+		214,
+		220,
+		231,
+		234,
+		242,
+		242,
+		247,
+		253,
+		264,
+		272,
+		272,
+		277,
+		283,
+		294,
+		297,
+		305,
+		305,
+		310,
+		316,
+		327,
+		330,
+		330,
+		335,
+		341,
+		352,
+		355,
+		363,
+		363,
+		368,
+		374,
+		385,
+		388,
+		396,
+		401,
+		407,
+		418,
+		421,
+		429,
+		429,
+		434,
+		440,
+		451,
+		454,
+		462,
+		462,
+		468,
+		479,
+		482,
+		490,
+		490,
+		495,
+		501,
+		512,
+		515,
+		523,
+		523,
+		528,
+		539,
+		542,
+		550,
+		550,
+		555,
+		561,
+		572,
+		575,
+		583,
+		583,
+		588,
+		594,
+		597,
+		605,
+		605,
+		610,
+		616,
+		627,
+		630,
+		638,
+		638,
+		643,
+		649,
+		660,
+		668,
+		668,
+		673,
+		679,
+		690,
+		693,
+		701,
+		701,
+		706,
+		712,
+		723,
+		726,
+		726,
+		731,
+		737,
+		748,
+		751,
+		759,
+		759,
+		764,
+		770,
+		781,
+		784,
+		792,
+		797,
+		803,
+		814,
+		817,
+		825,
+		825,
+		830,
+		836,
+		847,
+		850,
+		858,
+		858,
+		864,
+		875,
+		878,
+		886,
+		886,
+		891,
+		897,
+		908,
+		911,
+		919,
+		919,
+		924,
+		935,
+		938,
+		946,
+		946,
+		951,
+		957,
+		968,
+		971,
+		979,
+		979,
+		984,
+		990,
+		993,
+		1001,
+		1001,
+		1006,
+		1012,
+		1023,
+		1026,
+		1034,
+		1034,
+		1039,
+		1045,
+		1056,
+		1064,
+		1064,
+		1069,
+		1075,
+		1086,
+		1089,
+		1097,
+		1097,
+		1102,
+		1108,
+		1119,
+		1122,
+		1122,
+		1127,
+		1133,
+		1144,
+		1147,
+		1155,
+		1155,
+		1160,
+		1166,
+		1177,
+		1180,
+		1188,
+		1193,
+		1199,
+		1210,
+		1213,
+		1221,
+		1221,
+		1226,
+		1232,
+		1243,
+		1246,
+		1254,
+		1254,
+		1260,
+		1271,
+		1274,
+		1282,
+		1282,
+		1287,
+		1293,
+		1304,
+		1307,
+		1315,
+		1315,
+		1320,
+		1331,
+		1334,
+		1342,
+		1342,
+		1347,
+		1353,
+		1364,
+		1367,
+		1375,
+		1375,
+		1380,
+		1386,
+		1389,
+		1397,
+		1397,
+		1402,
+		1408,
+		1419,
+		1422,
+		1430,
+		1430,
+		1435,
+		1441,
+		1452,
+		1460,
+		1460,
+		1465,
+		1471,
+		1482,
+		1485,
+		1493,
+		1493,
+		1498,
+		1504,
+		1515,
+		1518,
+		1518,
+		1523,
+		1529,
+		1540,
+		1543,
+		1551,
+		1551,
+		1556,
+		1562,
+		1573,
+		1576,
+		1584,
+		1589,
+		1595,
+		1606,
+		1609,
+		1617,
+		1617,
+		1622,
+		1628,
+		1639,
+		1642,
+		1650,
+		1650,
+		1656,
+		1667,
+		1670,
+		1678,
+		1678,
+		1683,
+		1689,
+		1700,
+		1703,
+		1711,
+		1711,
+		1716,
+		1727,
+		1730,
+		1738,
+		1738,
+		1743,
+		1749,
+		1760,
+		1763,
+		1771,
+		1771,
+		1776,
+		1782,
+		1785,
+		1793,
+		1793,
+		1798,
+		1804,
+		1815,
+		1818,
+		1826,
+		1826,
+		1831,
+		1837,
+		1848,
+		1856,
+		1856,
+		1861,
+		1867,
+		1878,
+		1881,
+		1889,
+		1889,
+		1894,
+		1900,
+		1911,
+		1914,
+		1914,
+		1919,
+		1925,
+		1936,
+		1939,
+		1947,
+		1947,
+		1952,
+		1958,
+		1969,
+		1972,
+		1980,
+		1985,
+		1991,
+		2002,
+		2005,
+		2013,
+		2013,
+		2018,
+		2024,
+		2035,
+		2038,
+		2046,
+		2046,
+		2052,
+		2063,
+		2066,
+		2074,
+		2074,
+		2079,
+		2085,
+		2096,
+		2099,
+		2107,
+		2107,
+		2112,
+		2123,
+		2126,
+		2134,
+		2134,
+		2139,
+		2145,
+		2156,
+		2159,
+		2167,
+		2167,
+		2172,
+		2178,
+		2181,
+		2189,
+		2189,
+		2194,
+		2200,
+		2211,
+		2214,
+		2222,
+		2222,
+		2227,
+		2233,
+		2244,
+		2252,
+		2252,
+		2257,
+		2263,
+		2274,
+		2277,
+		2285,
+		2285,
+		2290,
+		2296,
+		2307,
+		2310,
+		2310,
+		2315,
+		2321,
+		2332,
+		2335,
+		2343,
+		2343,
+		2348,
+		2354,
+		2365,
+		2368,
+		2376,
+		2381,
+		2387,
+		2398,
+		2401,
+		2409,
+		2409,
+		2414,
+		2420,
+		2431,
+		2434,
+		2442,
+		2442,
+		2448,
+		2459,
+		2462,
+		2470,
+		2470,
+		2475,
+		2481,
+		2492,
+		2495,
+		2503,
+		2503,
+		2508,
+		2519,
+		2522,
+		2530,
+		2530,
+		2535,
+		2541,
+		2552,
+		2555,
+		2563,
+		2563,
+		2568,
+		2574,
+		2577,
+		2585,
+		2585,
+		2590,
+		2596,
+		2607,
+		2610,
+		2618,
+		2618,
+		2623,
+		2629,
+		2640,
+		2648,
+		2648,
+		2653,
+		2659,
+		2670,
+		2673,
+		2681,
+		2681,
+		2686,
+		2692,
+		2703,
+		2706,
+		2706,
+		2711,
+		2717,
+		2728,
+		2731,
+		2739,
+		2739,
+		2744,
+		2750,
+		2761,
+		2764,
+		2772,
+		2777,
+		2783,
+		2794,
+		2797,
+		2805,
+		2805,
+		2810,
+		2816,
+		2827,
+		2830,
+		2838,
+		2838,
+		2844,
+		2855,
+		2858,
+		2866,
+		2866,
+		2871,
+		2877,
+		2888,
+		2891,
+		2899,
+		2899,
+		2904,
+		2915,
+		2918,
+		2926,
+		2926,
+		2931,
+		2937,
+		2948,
+		2951,
+		2959,
+		2959,
+		2964,
+		2970,
+		2973,
+		2981,
+		2981,
+		2986,
+		2992,
+		3003,
+		3006,
+		3014,
+		3014,
+		3019,
+		3025,
+		3036,
+		3044,
+		3044,
+		3049,
+		3055,
+		3066,
+		3069,
+		3077,
+		3077,
+		3082,
+		3088,
+		3099,
+		3102,
+		3102,
+		3107,
+		3113,
+		3124,
+		3127,
+		3135,
+		3135,
+		3140,
+		3146,
+		3157,
+		3160,
+		3168,
+		3173,
+		3179,
+		3190,
+		3193,
+		3201,
+		3201,
+		3206,
+		3212,
+		3223,
+		3226,
+		3234,
+		3234,
+		3240,
+		3251,
+		3254,
+		3262,
+		3262,
+		3267,
+		3273,
+		3284,
+		3287,
+		3295,
+		3295,
+		3300,
+		3311,
+		3314,
+		3322,
+		3322,
+		3327,
+		3333,
+		3344,
+		3347,
+		3355,
+		3355,
+		3360,
+		3366,
+		3369,
+		3377,
+		3377,
+		3382,
+		3388,
+		3399,
+		3402,
+		3410,
+		3410,
+		3415,
+		3421,
+		3432,
+		3440,
+		3440,
+		3445,
+		3451,
+		3462,
+		3465,
+		3473,
+		3473,
+		3478,
+		3484,
+		3495,
+		3498,
+		3498,
+		3503,
+		3509,
+		3520,
+		3523,
+		3531,
+		3531,
+		3536,
+		3542,
+		3553,
+		3556,
+		3564,
+		3569,
+		3575,
+		3586,
+		3589,
+		3597,
+		3597,
+		3602,
+		3608,
+		3619,
+		3622,
+		3630,
+		3630,
+		3636,
+		3647,
+		3650,
+		3658,
+		3658,
+		3663,
+		3669,
+		3680,
+		3683,
+		3691,
+		3691,
+		3696,
+		3707,
+		3710,
+		3718,
+		3718,
+		3723,
+		3729,
+		3740,
+		3743,
+		3751,
+		3751,
+		3756,
+		3762,
+		3765,
+		3773,
+		3773,
+		3778,
+		3784,
+		3795,
+		3798,
+		3806,
+		3806,
+		3811,
+		3817,
+		3828,
+		3836,
+		3836,
+		3841,
+		3847,
+		3858,
+		3861,
+		3869,
+		3869,
+		3874,
+		3880,
+		3891,
+		3894,
+		3894,
+		3899,
+		3905,
+		3916,
+		3919,
+		3927,
+		3927,
+		3932,
+		3938,
+		3949,
+		3952,
+		3960,
+		3965,
+		3971,
+		3982,
+		3985,
+		3993,
+		3993,
+		3998,
+		4004,
+		4015,
+		4018,
+		4026,
+		4026,
+		4032,
+		4043,
+		4046,
+		4054,
+		4054,
+		4059,
+		4065,
+		4076,
+		4079,
+		4087,
+		4087,
+		4092,
+		4103,
+		4106,
+		4114,
+		4114,
+		4119,
+		4125,
+		4136,
+		4139,
+		4147,
+		4147,
+		4152,
+		4158,
+		4161,
+		4169,
+		4169,
+		4174,
+		4180,
+		4191,
+		4194,
+		4202,
+		4202,
+		4207,
+		4213,
+		4224,
+		4232,
+		4232,
+		4237,
+		4243,
+		4254,
+		4257,
+		4265,
+		4265,
+		4270,
+		4276,
+		4287,
+		4290,
+		4290,
+		4295,
+		4301,
+		4312,
+		4315,
+		4323,
+		4323,
+		4328,
+		4334,
+		4345,
+		4348,
+		4356,
+		4361,
+		4367,
+		4378,
+		4381,
+		4389,
+		4389,
+		4394,
+		4400,
+		4411,
+		4414,
+		4422,
+		4422,
+		4428,
+		4439,
+		4442,
+		4450,
+		4450,
+		4455,
+		4461,
+		4472,
+		4475,
+		4483,
+		4483,
+		4488,
+		4499,
+		4502,
+		4510,
+		4510,
+		4515,
+		4521,
+		4532,
+		4535,
+		4543,
+		4543,
+		4548,
+		4554,
+		4557,
+		4565,
+		4565,
+		4570,
+		4576,
+		4587,
+		4590,
+		4598,
+		4598,
+		4603,
+		4609,
+		4620,
+		4628,
+		4628,
+		4633,
+		4639,
+		4650,
+		4653,
+		4661,
+		4661,
+		4666,
+		4672,
+		4683,
+		4686,
+		4686,
+		4691,
+		4697,
+		4708,
+		4711,
+		4719,
+		4719,
+		4724,
+		4730,
+		4741,
+		4744,
+		4752,
+		4757,
+		4763,
+		4774,
+		4777,
+		4785,
+		4785,
+		4790,
+		4796,
+		4807,
+		4810,
+		4818,
+		4818,
+		4824,
+		4835,
+		4838,
+		4846,
+		4846,
+		4851,
+		4857,
+		4868,
+		4871,
+		4879,
+		4879,
+		4884,
+		4895,
+		4898,
+		4906,
+		4906,
+		4911,
+		4917,
+		4928,
+		4931,
+		4939,
+		4939,
+		4944,
+		4950,
+		4953,
+		4961,
+		4961,
+		4966,
+		4972,
+		4983,
+		4986,
+		4994,
+		4994,
+		4999,
+		5005,
+		5016,
+		5024,
+		5024,
+		5029,
+		5035,
+		5046,
+		5049,
+		5057,
+		5057,
+		5062,
+		5068,
+	],
+	values: "alpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήτα\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalphagamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"ε\nζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\deltaζήταalpha\"beta\"gamma\\deltaε\nζήταalpha\"beta\"gamma\\delta",
+};
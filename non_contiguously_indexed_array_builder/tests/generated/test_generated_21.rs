@@ -0,0 +1,32 @@
+use non_contiguously_indexed_array::NciArray;
+
+pub const GENERATED_21: NciArray<(::core::net::Ipv4Addr, ::core::num::NonZeroU16), u16> = NciArray {
+	segments_idx_begin: &[
+		(::core::net::Ipv4Addr::new(10, 0, 0, 1), ::core::num::NonZeroU16::new(1).unwrap()),
+		(::core::net::Ipv4Addr::new(10, 0, 0, 1), ::core::num::NonZeroU16::new(80).unwrap()),
+		(::core::net::Ipv4Addr::new(10, 0, 0, 1), ::core::num::NonZeroU16::new(443).unwrap()),
+		(::core::net::Ipv4Addr::new(192, 168, 0, 1), ::core::num::NonZeroU16::new(1).unwrap()),
+		(::core::net::Ipv4Addr::new(192, 168, 0, 1), ::core::num::NonZeroU16::new(80).unwrap()),
+		(::core::net::Ipv4Addr::new(192, 168, 0, 1), ::core::num::NonZeroU16::new(443).unwrap()),
+	],
+	segments_mem_idx_begin: &[
+		0,
+		3,
+		4,
+		5,
+		8,
+		9,
+	],
+	values: &[
+		1,
+		2,
+		3,
+		80,
+		443,
+		1,
+		2,
+		3,
+		80,
+		443,
+	],
+};
@@ -0,0 +1,11 @@
+use non_contiguously_indexed_array::NciBitmapArray;
+
+pub const GENERATED_22: NciBitmapArray<u8, u8> = NciBitmapArray {
+	idx_begin: None,
+	bitmap: &[
+	],
+	rank_samples: &[
+	],
+	values: &[
+	],
+};
@@ -0,0 +1,410 @@
+use non_contiguously_indexed_array::NciEytzingerArray;
+
+pub const GENERATED_6: NciEytzingerArray<u16, u16> = NciEytzingerArray {
+	segments_idx_begin: &[
+		189,
+		93,
+		252,
+		45,
+		141,
+		228,
+		276,
+		21,
+		69,
+		117,
+		165,
+		213,
+		240,
+		264,
+		288,
+		9,
+		33,
+		57,
+		81,
+		105,
+		129,
+		153,
+		177,
+		201,
+		222,
+		234,
+		246,
+		258,
+		270,
+		282,
+		294,
+		3,
+		15,
+		27,
+		39,
+		51,
+		63,
+		75,
+		87,
+		99,
+		111,
+		123,
+		135,
+		147,
+		159,
+		171,
+		183,
+		195,
+		207,
+		219,
+		225,
+		231,
+		237,
+		243,
+		249,
+		255,
+		261,
+		267,
+		273,
+		279,
+		285,
+		291,
+		297,
+		0,
+		6,
+		12,
+		18,
+		24,
+		30,
+		36,
+		42,
+		48,
+		54,
+		60,
+		66,
+		72,
+		78,
+		84,
+		90,
+		96,
+		102,
+		108,
+		114,
+		120,
+		126,
+		132,
+		138,
+		144,
+		150,
+		156,
+		162,
+		168,
+		174,
+		180,
+		186,
+		192,
+		198,
+		204,
+		210,
+		216,
+	],
+	segments_mem_idx_begin: &[
+		126,
+		62,
+		168,
+		30,
+		94,
+		152,
+		184,
+		14,
+		46,
+		78,
+		110,
+		142,
+		160,
+		176,
+		192,
+		6,
+		22,
+		38,
+		54,
+		70,
+		86,
+		102,
+		118,
+		134,
+		148,
+		156,
+		164,
+		172,
+		180,
+		188,
+		196,
+		2,
+		10,
+		18,
+		26,
+		34,
+		42,
+		50,
+		58,
+		66,
+		74,
+		82,
+		90,
+		98,
+		106,
+		114,
+		122,
+		130,
+		138,
+		146,
+		150,
+		154,
+		158,
+		162,
+		166,
+		170,
+		174,
+		178,
+		182,
+		186,
+		190,
+		194,
+		198,
+		0,
+		4,
+		8,
+		12,
+		16,
+		20,
+		24,
+		28,
+		32,
+		36,
+		40,
+		44,
+		48,
+		52,
+		56,
+		60,
+		64,
+		68,
+		72,
+		76,
+		80,
+		84,
+		88,
+		92,
+		96,
+		100,
+		104,
+		108,
+		112,
+		116,
+		120,
+		124,
+		128,
+		132,
+		136,
+		140,
+		144,
+	],
+	values: &[
+		0,
+		0,
+		1,
+		1,
+		2,
+		2,
+		3,
+		3,
+		4,
+		4,
+		5,
+		5,
+		6,
+		6,
+		7,
+		7,
+		8,
+		8,
+		9,
+		9,
+		10,
+		10,
+		11,
+		11,
+		12,
+		12,
+		13,
+		13,
+		14,
+		14,
+		15,
+		15,
+		16,
+		16,
+		17,
+		17,
+		18,
+		18,
+		19,
+		19,
+		20,
+		20,
+		21,
+		21,
+		22,
+		22,
+		23,
+		23,
+		24,
+		24,
+		25,
+		25,
+		26,
+		26,
+		27,
+		27,
+		28,
+		28,
+		29,
+		29,
+		30,
+		30,
+		31,
+		31,
+		32,
+		32,
+		33,
+		33,
+		34,
+		34,
+		35,
+		35,
+		36,
+		36,
+		37,
+		37,
+		38,
+		38,
+		39,
+		39,
+		40,
+		40,
+		41,
+		41,
+		42,
+		42,
+		43,
+		43,
+		44,
+		44,
+		45,
+		45,
+		46,
+		46,
+		47,
+		47,
+		48,
+		48,
+		49,
+		49,
+		50,
+		50,
+		51,
+		51,
+		52,
+		52,
+		53,
+		53,
+		54,
+		54,
+		55,
+		55,
+		56,
+		56,
+		57,
+		57,
+		58,
+		58,
+		59,
+		59,
+		60,
+		60,
+		61,
+		61,
+		62,
+		62,
+		63,
+		63,
+		64,
+		64,
+		65,
+		65,
+		66,
+		66,
+		67,
+		67,
+		68,
+		68,
+		69,
+		69,
+		70,
+		70,
+		71,
+		71,
+		72,
+		72,
+		73,
+		73,
+		74,
+		74,
+		75,
+		75,
+		76,
+		76,
+		77,
+		77,
+		78,
+		78,
+		79,
+		79,
+		80,
+		80,
+		81,
+		81,
+		82,
+		82,
+		83,
+		83,
+		84,
+		84,
+		85,
+		85,
+		86,
+		86,
+		87,
+		87,
+		88,
+		88,
+		89,
+		89,
+		90,
+		90,
+		91,
+		91,
+		92,
+		92,
+		93,
+		93,
+		94,
+		94,
+		95,
+		95,
+		96,
+		96,
+		97,
+		97,
+		98,
+		98,
+		99,
+		99,
+	],
+};
@@ -0,0 +1,2410 @@
+use non_contiguously_indexed_array::NciBucketArray;
+
+pub const GENERATED_7: NciBucketArray<u32, u32> = NciBucketArray {
+	array: ::non_contiguously_indexed_array::NciArray {
+		segments_idx_begin: &[
+			0,
+			13,
+			52,
+			117,
+			208,
+			325,
+			468,
+			637,
+			832,
+			1053,
+			1300,
+			1573,
+			1872,
+			2197,
+			2548,
+			2925,
+			3328,
+			3757,
+			4212,
+			4693,
+			5200,
+			5733,
+			6292,
+			6877,
+			7488,
+			8125,
+			8788,
+			9477,
+			10192,
+			10933,
+			11700,
+			12493,
+			13312,
+			14157,
+			15028,
+			15925,
+			16848,
+			17797,
+			18772,
+			19773,
+			20800,
+			21853,
+			22932,
+			24037,
+			25168,
+			26325,
+			27508,
+			28717,
+			29952,
+			31213,
+			32500,
+			33813,
+			35152,
+			36517,
+			37908,
+			39325,
+			40768,
+			42237,
+			43732,
+			45253,
+			46800,
+			48373,
+			49972,
+			51597,
+			53248,
+			54925,
+			56628,
+			58357,
+			60112,
+			61893,
+			63700,
+			65533,
+			67392,
+			69277,
+			71188,
+			73125,
+			75088,
+			77077,
+			79092,
+			81133,
+			83200,
+			85293,
+			87412,
+			89557,
+			91728,
+			93925,
+			96148,
+			98397,
+			100672,
+			102973,
+			105300,
+			107653,
+			110032,
+			112437,
+			114868,
+			117325,
+			119808,
+			122317,
+			124852,
+			127413,
+			130000,
+			132613,
+			135252,
+			137917,
+			140608,
+			143325,
+			146068,
+			148837,
+			151632,
+			154453,
+			157300,
+			160173,
+			163072,
+			165997,
+			168948,
+			171925,
+			174928,
+			177957,
+			181012,
+			184093,
+			187200,
+			190333,
+			193492,
+			196677,
+			199888,
+			203125,
+			206388,
+			209677,
+			212992,
+			216333,
+			219700,
+			223093,
+			226512,
+			229957,
+			233428,
+			236925,
+			240448,
+			243997,
+			247572,
+			251173,
+			254800,
+			258453,
+			262132,
+			265837,
+			269568,
+			273325,
+			277108,
+			280917,
+			284752,
+			288613,
+			292500,
+			296413,
+			300352,
+			304317,
+			308308,
+			312325,
+			316368,
+			320437,
+			324532,
+			328653,
+			332800,
+			336973,
+			341172,
+			345397,
+			349648,
+			353925,
+			358228,
+			362557,
+			366912,
+			371293,
+			375700,
+			380133,
+			384592,
+			389077,
+			393588,
+			398125,
+			402688,
+			407277,
+			411892,
+			416533,
+			421200,
+			425893,
+			430612,
+			435357,
+			440128,
+			444925,
+			449748,
+			454597,
+			459472,
+			464373,
+			469300,
+			474253,
+			479232,
+			484237,
+			489268,
+			494325,
+			499408,
+			504517,
+			509652,
+			514813,
+			520000,
+			525213,
+			530452,
+			535717,
+			541008,
+			546325,
+			551668,
+			557037,
+			562432,
+			567853,
+			573300,
+			578773,
+			584272,
+			589797,
+			595348,
+			600925,
+			606528,
+			612157,
+			617812,
+			623493,
+			629200,
+			634933,
+			640692,
+			646477,
+			652288,
+			658125,
+			663988,
+			669877,
+			675792,
+			681733,
+			687700,
+			693693,
+			699712,
+			705757,
+			711828,
+			717925,
+			724048,
+			730197,
+			736372,
+			742573,
+			748800,
+			755053,
+			761332,
+			767637,
+			773968,
+			780325,
+			786708,
+			793117,
+			799552,
+			806013,
+			812500,
+			819013,
+			825552,
+			832117,
+			838708,
+			845325,
+			851968,
+			858637,
+			865332,
+			872053,
+			878800,
+			885573,
+			892372,
+			899197,
+			906048,
+			912925,
+			919828,
+			926757,
+			933712,
+			940693,
+			947700,
+			954733,
+			961792,
+			968877,
+			975988,
+			983125,
+			990288,
+			997477,
+			1004692,
+			1011933,
+			1019200,
+			1026493,
+			1033812,
+			1041157,
+			1048528,
+			1055925,
+			1063348,
+			1070797,
+			1078272,
+			1085773,
+			1093300,
+			1100853,
+			1108432,
+			1116037,
+			1123668,
+			1131325,
+			1139008,
+			1146717,
+			1154452,
+			1162213,
+			1170000,
+			1177813,
+			1185652,
+			1193517,
+			1201408,
+			1209325,
+			1217268,
+			1225237,
+			1233232,
+			1241253,
+			1249300,
+			1257373,
+			1265472,
+			1273597,
+			1281748,
+			1289925,
+			1298128,
+			1306357,
+			1314612,
+			1322893,
+			1331200,
+			1339533,
+			1347892,
+			1356277,
+			1364688,
+			1373125,
+			1381588,
+			1390077,
+			1398592,
+			1407133,
+			1415700,
+			1424293,
+			1432912,
+			1441557,
+			1450228,
+			1458925,
+			1467648,
+			1476397,
+			1485172,
+			1493973,
+			1502800,
+			1511653,
+			1520532,
+			1529437,
+			1538368,
+			1547325,
+			1556308,
+			1565317,
+			1574352,
+			1583413,
+			1592500,
+			1601613,
+			1610752,
+			1619917,
+			1629108,
+			1638325,
+			1647568,
+			1656837,
+			1666132,
+			1675453,
+			1684800,
+			1694173,
+			1703572,
+			1712997,
+			1722448,
+			1731925,
+			1741428,
+			1750957,
+			1760512,
+			1770093,
+			1779700,
+			1789333,
+			1798992,
+			1808677,
+			1818388,
+			1828125,
+			1837888,
+			1847677,
+			1857492,
+			1867333,
+			1877200,
+			1887093,
+			1897012,
+			1906957,
+			1916928,
+			1926925,
+			1936948,
+			1946997,
+			1957072,
+			1967173,
+			1977300,
+			1987453,
+			1997632,
+			2007837,
+			2018068,
+			2028325,
+			2038608,
+			2048917,
+			2059252,
+			2069613,
+			2080000,
+			2090413,
+			2100852,
+			2111317,
+			2121808,
+			2132325,
+			2142868,
+			2153437,
+			2164032,
+			2174653,
+			2185300,
+			2195973,
+			2206672,
+			2217397,
+			2228148,
+			2238925,
+			2249728,
+			2260557,
+			2271412,
+			2282293,
+			2293200,
+			2304133,
+			2315092,
+			2326077,
+			2337088,
+			2348125,
+			2359188,
+			2370277,
+			2381392,
+			2392533,
+			2403700,
+			2414893,
+			2426112,
+			2437357,
+			2448628,
+			2459925,
+			2471248,
+			2482597,
+			2493972,
+			2505373,
+			2516800,
+			2528253,
+			2539732,
+			2551237,
+			2562768,
+			2574325,
+			2585908,
+			2597517,
+			2609152,
+			2620813,
+			2632500,
+			2644213,
+			2655952,
+			2667717,
+			2679508,
+			2691325,
+			2703168,
+			2715037,
+			2726932,
+			2738853,
+			2750800,
+			2762773,
+			2774772,
+			2786797,
+			2798848,
+			2810925,
+			2823028,
+			2835157,
+			2847312,
+			2859493,
+			2871700,
+			2883933,
+			2896192,
+			2908477,
+			2920788,
+			2933125,
+			2945488,
+			2957877,
+			2970292,
+			2982733,
+			2995200,
+			3007693,
+			3020212,
+			3032757,
+			3045328,
+			3057925,
+			3070548,
+			3083197,
+			3095872,
+			3108573,
+			3121300,
+			3134053,
+			3146832,
+			3159637,
+			3172468,
+			3185325,
+			3198208,
+			3211117,
+			3224052,
+			3237013,
+		],
+		segments_mem_idx_begin: &[
+			0,
+			1,
+			3,
+			6,
+			7,
+			9,
+			12,
+			13,
+			15,
+			18,
+			19,
+			21,
+			24,
+			25,
+			27,
+			30,
+			31,
+			33,
+			36,
+			37,
+			39,
+			42,
+			43,
+			45,
+			48,
+			49,
+			51,
+			54,
+			55,
+			57,
+			60,
+			61,
+			63,
+			66,
+			67,
+			69,
+			72,
+			73,
+			75,
+			78,
+			79,
+			81,
+			84,
+			85,
+			87,
+			90,
+			91,
+			93,
+			96,
+			97,
+			99,
+			102,
+			103,
+			105,
+			108,
+			109,
+			111,
+			114,
+			115,
+			117,
+			120,
+			121,
+			123,
+			126,
+			127,
+			129,
+			132,
+			133,
+			135,
+			138,
+			139,
+			141,
+			144,
+			145,
+			147,
+			150,
+			151,
+			153,
+			156,
+			157,
+			159,
+			162,
+			163,
+			165,
+			168,
+			169,
+			171,
+			174,
+			175,
+			177,
+			180,
+			181,
+			183,
+			186,
+			187,
+			189,
+			192,
+			193,
+			195,
+			198,
+			199,
+			201,
+			204,
+			205,
+			207,
+			210,
+			211,
+			213,
+			216,
+			217,
+			219,
+			222,
+			223,
+			225,
+			228,
+			229,
+			231,
+			234,
+			235,
+			237,
+			240,
+			241,
+			243,
+			246,
+			247,
+			249,
+			252,
+			253,
+			255,
+			258,
+			259,
+			261,
+			264,
+			265,
+			267,
+			270,
+			271,
+			273,
+			276,
+			277,
+			279,
+			282,
+			283,
+			285,
+			288,
+			289,
+			291,
+			294,
+			295,
+			297,
+			300,
+			301,
+			303,
+			306,
+			307,
+			309,
+			312,
+			313,
+			315,
+			318,
+			319,
+			321,
+			324,
+			325,
+			327,
+			330,
+			331,
+			333,
+			336,
+			337,
+			339,
+			342,
+			343,
+			345,
+			348,
+			349,
+			351,
+			354,
+			355,
+			357,
+			360,
+			361,
+			363,
+			366,
+			367,
+			369,
+			372,
+			373,
+			375,
+			378,
+			379,
+			381,
+			384,
+			385,
+			387,
+			390,
+			391,
+			393,
+			396,
+			397,
+			399,
+			402,
+			403,
+			405,
+			408,
+			409,
+			411,
+			414,
+			415,
+			417,
+			420,
+			421,
+			423,
+			426,
+			427,
+			429,
+			432,
+			433,
+			435,
+			438,
+			439,
+			441,
+			444,
+			445,
+			447,
+			450,
+			451,
+			453,
+			456,
+			457,
+			459,
+			462,
+			463,
+			465,
+			468,
+			469,
+			471,
+			474,
+			475,
+			477,
+			480,
+			481,
+			483,
+			486,
+			487,
+			489,
+			492,
+			493,
+			495,
+			498,
+			499,
+			501,
+			504,
+			505,
+			507,
+			510,
+			511,
+			513,
+			516,
+			517,
+			519,
+			522,
+			523,
+			525,
+			528,
+			529,
+			531,
+			534,
+			535,
+			537,
+			540,
+			541,
+			543,
+			546,
+			547,
+			549,
+			552,
+			553,
+			555,
+			558,
+			559,
+			561,
+			564,
+			565,
+			567,
+			570,
+			571,
+			573,
+			576,
+			577,
+			579,
+			582,
+			583,
+			585,
+			588,
+			589,
+			591,
+			594,
+			595,
+			597,
+			600,
+			601,
+			603,
+			606,
+			607,
+			609,
+			612,
+			613,
+			615,
+			618,
+			619,
+			621,
+			624,
+			625,
+			627,
+			630,
+			631,
+			633,
+			636,
+			637,
+			639,
+			642,
+			643,
+			645,
+			648,
+			649,
+			651,
+			654,
+			655,
+			657,
+			660,
+			661,
+			663,
+			666,
+			667,
+			669,
+			672,
+			673,
+			675,
+			678,
+			679,
+			681,
+			684,
+			685,
+			687,
+			690,
+			691,
+			693,
+			696,
+			697,
+			699,
+			702,
+			703,
+			705,
+			708,
+			709,
+			711,
+			714,
+			715,
+			717,
+			720,
+			721,
+			723,
+			726,
+			727,
+			729,
+			732,
+			733,
+			735,
+			738,
+			739,
+			741,
+			744,
+			745,
+			747,
+			750,
+			751,
+			753,
+			756,
+			757,
+			759,
+			762,
+			763,
+			765,
+			768,
+			769,
+			771,
+			774,
+			775,
+			777,
+			780,
+			781,
+			783,
+			786,
+			787,
+			789,
+			792,
+			793,
+			795,
+			798,
+			799,
+			801,
+			804,
+			805,
+			807,
+			810,
+			811,
+			813,
+			816,
+			817,
+			819,
+			822,
+			823,
+			825,
+			828,
+			829,
+			831,
+			834,
+			835,
+			837,
+			840,
+			841,
+			843,
+			846,
+			847,
+			849,
+			852,
+			853,
+			855,
+			858,
+			859,
+			861,
+			864,
+			865,
+			867,
+			870,
+			871,
+			873,
+			876,
+			877,
+			879,
+			882,
+			883,
+			885,
+			888,
+			889,
+			891,
+			894,
+			895,
+			897,
+			900,
+			901,
+			903,
+			906,
+			907,
+			909,
+			912,
+			913,
+			915,
+			918,
+			919,
+			921,
+			924,
+			925,
+			927,
+			930,
+			931,
+			933,
+			936,
+			937,
+			939,
+			942,
+			943,
+			945,
+			948,
+			949,
+			951,
+			954,
+			955,
+			957,
+			960,
+			961,
+			963,
+			966,
+			967,
+			969,
+			972,
+			973,
+			975,
+			978,
+			979,
+			981,
+			984,
+			985,
+			987,
+			990,
+			991,
+			993,
+			996,
+			997,
+		],
+		values: &[
+			0,
+			1,
+			2,
+			2,
+			3,
+			4,
+			3,
+			4,
+			5,
+			5,
+			6,
+			7,
+			6,
+			7,
+			8,
+			8,
+			9,
+			10,
+			9,
+			10,
+			11,
+			11,
+			12,
+			13,
+			12,
+			13,
+			14,
+			14,
+			15,
+			16,
+			15,
+			16,
+			17,
+			17,
+			18,
+			19,
+			18,
+			19,
+			20,
+			20,
+			21,
+			22,
+			21,
+			22,
+			23,
+			23,
+			24,
+			25,
+			24,
+			25,
+			26,
+			26,
+			27,
+			28,
+			27,
+			28,
+			29,
+			29,
+			30,
+			31,
+			30,
+			31,
+			32,
+			32,
+			33,
+			34,
+			33,
+			34,
+			35,
+			35,
+			36,
+			37,
+			36,
+			37,
+			38,
+			38,
+			39,
+			40,
+			39,
+			40,
+			41,
+			41,
+			42,
+			43,
+			42,
+			43,
+			44,
+			44,
+			45,
+			46,
+			45,
+			46,
+			47,
+			47,
+			48,
+			49,
+			48,
+			49,
+			50,
+			50,
+			51,
+			52,
+			51,
+			52,
+			53,
+			53,
+			54,
+			55,
+			54,
+			55,
+			56,
+			56,
+			57,
+			58,
+			57,
+			58,
+			59,
+			59,
+			60,
+			61,
+			60,
+			61,
+			62,
+			62,
+			63,
+			64,
+			63,
+			64,
+			65,
+			65,
+			66,
+			67,
+			66,
+			67,
+			68,
+			68,
+			69,
+			70,
+			69,
+			70,
+			71,
+			71,
+			72,
+			73,
+			72,
+			73,
+			74,
+			74,
+			75,
+			76,
+			75,
+			76,
+			77,
+			77,
+			78,
+			79,
+			78,
+			79,
+			80,
+			80,
+			81,
+			82,
+			81,
+			82,
+			83,
+			83,
+			84,
+			85,
+			84,
+			85,
+			86,
+			86,
+			87,
+			88,
+			87,
+			88,
+			89,
+			89,
+			90,
+			91,
+			90,
+			91,
+			92,
+			92,
+			93,
+			94,
+			93,
+			94,
+			95,
+			95,
+			96,
+			97,
+			96,
+			97,
+			98,
+			98,
+			99,
+			100,
+			99,
+			100,
+			101,
+			101,
+			102,
+			103,
+			102,
+			103,
+			104,
+			104,
+			105,
+			106,
+			105,
+			106,
+			107,
+			107,
+			108,
+			109,
+			108,
+			109,
+			110,
+			110,
+			111,
+			112,
+			111,
+			112,
+			113,
+			113,
+			114,
+			115,
+			114,
+			115,
+			116,
+			116,
+			117,
+			118,
+			117,
+			118,
+			119,
+			119,
+			120,
+			121,
+			120,
+			121,
+			122,
+			122,
+			123,
+			124,
+			123,
+			124,
+			125,
+			125,
+			126,
+			127,
+			126,
+			127,
+			128,
+			128,
+			129,
+			130,
+			129,
+			130,
+			131,
+			131,
+			132,
+			133,
+			132,
+			133,
+			134,
+			134,
+			135,
+			136,
+			135,
+			136,
+			137,
+			137,
+			138,
+			139,
+			138,
+			139,
+			140,
+			140,
+			141,
+			142,
+			141,
+			142,
+			143,
+			143,
+			144,
+			145,
+			144,
+			145,
+			146,
+			146,
+			147,
+			148,
+			147,
+			148,
+			149,
+			149,
+			150,
+			151,
+			150,
+			151,
+			152,
+			152,
+			153,
+			154,
+			153,
+			154,
+			155,
+			155,
+			156,
+			157,
+			156,
+			157,
+			158,
+			158,
+			159,
+			160,
+			159,
+			160,
+			161,
+			161,
+			162,
+			163,
+			162,
+			163,
+			164,
+			164,
+			165,
+			166,
+			165,
+			166,
+			167,
+			167,
+			168,
+			169,
+			168,
+			169,
+			170,
+			170,
+			171,
+			172,
+			171,
+			172,
+			173,
+			173,
+			174,
+			175,
+			174,
+			175,
+			176,
+			176,
+			177,
+			178,
+			177,
+			178,
+			179,
+			179,
+			180,
+			181,
+			180,
+			181,
+			182,
+			182,
+			183,
+			184,
+			183,
+			184,
+			185,
+			185,
+			186,
+			187,
+			186,
+			187,
+			188,
+			188,
+			189,
+			190,
+			189,
+			190,
+			191,
+			191,
+			192,
+			193,
+			192,
+			193,
+			194,
+			194,
+			195,
+			196,
+			195,
+			196,
+			197,
+			197,
+			198,
+			199,
+			198,
+			199,
+			200,
+			200,
+			201,
+			202,
+			201,
+			202,
+			203,
+			203,
+			204,
+			205,
+			204,
+			205,
+			206,
+			206,
+			207,
+			208,
+			207,
+			208,
+			209,
+			209,
+			210,
+			211,
+			210,
+			211,
+			212,
+			212,
+			213,
+			214,
+			213,
+			214,
+			215,
+			215,
+			216,
+			217,
+			216,
+			217,
+			218,
+			218,
+			219,
+			220,
+			219,
+			220,
+			221,
+			221,
+			222,
+			223,
+			222,
+			223,
+			224,
+			224,
+			225,
+			226,
+			225,
+			226,
+			227,
+			227,
+			228,
+			229,
+			228,
+			229,
+			230,
+			230,
+			231,
+			232,
+			231,
+			232,
+			233,
+			233,
+			234,
+			235,
+			234,
+			235,
+			236,
+			236,
+			237,
+			238,
+			237,
+			238,
+			239,
+			239,
+			240,
+			241,
+			240,
+			241,
+			242,
+			242,
+			243,
+			244,
+			243,
+			244,
+			245,
+			245,
+			246,
+			247,
+			246,
+			247,
+			248,
+			248,
+			249,
+			250,
+			249,
+			250,
+			251,
+			251,
+			252,
+			253,
+			252,
+			253,
+			254,
+			254,
+			255,
+			256,
+			255,
+			256,
+			257,
+			257,
+			258,
+			259,
+			258,
+			259,
+			260,
+			260,
+			261,
+			262,
+			261,
+			262,
+			263,
+			263,
+			264,
+			265,
+			264,
+			265,
+			266,
+			266,
+			267,
+			268,
+			267,
+			268,
+			269,
+			269,
+			270,
+			271,
+			270,
+			271,
+			272,
+			272,
+			273,
+			274,
+			273,
+			274,
+			275,
+			275,
+			276,
+			277,
+			276,
+			277,
+			278,
+			278,
+			279,
+			280,
+			279,
+			280,
+			281,
+			281,
+			282,
+			283,
+			282,
+			283,
+			284,
+			284,
+			285,
+			286,
+			285,
+			286,
+			287,
+			287,
+			288,
+			289,
+			288,
+			289,
+			290,
+			290,
+			291,
+			292,
+			291,
+			292,
+			293,
+			293,
+			294,
+			295,
+			294,
+			295,
+			296,
+			296,
+			297,
+			298,
+			297,
+			298,
+			299,
+			299,
+			300,
+			301,
+			300,
+			301,
+			302,
+			302,
+			303,
+			304,
+			303,
+			304,
+			305,
+			305,
+			306,
+			307,
+			306,
+			307,
+			308,
+			308,
+			309,
+			310,
+			309,
+			310,
+			311,
+			311,
+			312,
+			313,
+			312,
+			313,
+			314,
+			314,
+			315,
+			316,
+			315,
+			316,
+			317,
+			317,
+			318,
+			319,
+			318,
+			319,
+			320,
+			320,
+			321,
+			322,
+			321,
+			322,
+			323,
+			323,
+			324,
+			325,
+			324,
+			325,
+			326,
+			326,
+			327,
+			328,
+			327,
+			328,
+			329,
+			329,
+			330,
+			331,
+			330,
+			331,
+			332,
+			332,
+			333,
+			334,
+			333,
+			334,
+			335,
+			335,
+			336,
+			337,
+			336,
+			337,
+			338,
+			338,
+			339,
+			340,
+			339,
+			340,
+			341,
+			341,
+			342,
+			343,
+			342,
+			343,
+			344,
+			344,
+			345,
+			346,
+			345,
+			346,
+			347,
+			347,
+			348,
+			349,
+			348,
+			349,
+			350,
+			350,
+			351,
+			352,
+			351,
+			352,
+			353,
+			353,
+			354,
+			355,
+			354,
+			355,
+			356,
+			356,
+			357,
+			358,
+			357,
+			358,
+			359,
+			359,
+			360,
+			361,
+			360,
+			361,
+			362,
+			362,
+			363,
+			364,
+			363,
+			364,
+			365,
+			365,
+			366,
+			367,
+			366,
+			367,
+			368,
+			368,
+			369,
+			370,
+			369,
+			370,
+			371,
+			371,
+			372,
+			373,
+			372,
+			373,
+			374,
+			374,
+			375,
+			376,
+			375,
+			376,
+			377,
+			377,
+			378,
+			379,
+			378,
+			379,
+			380,
+			380,
+			381,
+			382,
+			381,
+			382,
+			383,
+			383,
+			384,
+			385,
+			384,
+			385,
+			386,
+			386,
+			387,
+			388,
+			387,
+			388,
+			389,
+			389,
+			390,
+			391,
+			390,
+			391,
+			392,
+			392,
+			393,
+			394,
+			393,
+			394,
+			395,
+			395,
+			396,
+			397,
+			396,
+			397,
+			398,
+			398,
+			399,
+			400,
+			399,
+			400,
+			401,
+			401,
+			402,
+			403,
+			402,
+			403,
+			404,
+			404,
+			405,
+			406,
+			405,
+			406,
+			407,
+			407,
+			408,
+			409,
+			408,
+			409,
+			410,
+			410,
+			411,
+			412,
+			411,
+			412,
+			413,
+			413,
+			414,
+			415,
+			414,
+			415,
+			416,
+			416,
+			417,
+			418,
+			417,
+			418,
+			419,
+			419,
+			420,
+			421,
+			420,
+			421,
+			422,
+			422,
+			423,
+			424,
+			423,
+			424,
+			425,
+			425,
+			426,
+			427,
+			426,
+			427,
+			428,
+			428,
+			429,
+			430,
+			429,
+			430,
+			431,
+			431,
+			432,
+			433,
+			432,
+			433,
+			434,
+			434,
+			435,
+			436,
+			435,
+			436,
+			437,
+			437,
+			438,
+			439,
+			438,
+			439,
+			440,
+			440,
+			441,
+			442,
+			441,
+			442,
+			443,
+			443,
+			444,
+			445,
+			444,
+			445,
+			446,
+			446,
+			447,
+			448,
+			447,
+			448,
+			449,
+			449,
+			450,
+			451,
+			450,
+			451,
+			452,
+			452,
+			453,
+			454,
+			453,
+			454,
+			455,
+			455,
+			456,
+			457,
+			456,
+			457,
+			458,
+			458,
+			459,
+			460,
+			459,
+			460,
+			461,
+			461,
+			462,
+			463,
+			462,
+			463,
+			464,
+			464,
+			465,
+			466,
+			465,
+			466,
+			467,
+			467,
+			468,
+			469,
+			468,
+			469,
+			470,
+			470,
+			471,
+			472,
+			471,
+			472,
+			473,
+			473,
+			474,
+			475,
+			474,
+			475,
+			476,
+			476,
+			477,
+			478,
+			477,
+			478,
+			479,
+			479,
+			480,
+			481,
+			480,
+			481,
+			482,
+			482,
+			483,
+			484,
+			483,
+			484,
+			485,
+			485,
+			486,
+			487,
+			486,
+			487,
+			488,
+			488,
+			489,
+			490,
+			489,
+			490,
+			491,
+			491,
+			492,
+			493,
+			492,
+			493,
+			494,
+			494,
+			495,
+			496,
+			495,
+			496,
+			497,
+			497,
+			498,
+			499,
+			498,
+			499,
+			500,
+		],
+	},
+	bucket_shift: 13,
+	bucket_segments: &[
+		0,
+		25,
+		35,
+		43,
+		50,
+		56,
+		61,
+		66,
+		71,
+		75,
+		79,
+		83,
+		86,
+		90,
+		93,
+		97,
+		100,
+		103,
+		106,
+		109,
+		112,
+		115,
+		117,
+		120,
+		122,
+		125,
+		128,
+		130,
+		132,
+		135,
+		137,
+		139,
+		142,
+		144,
+		146,
+		148,
+		150,
+		152,
+		154,
+		156,
+		158,
+		160,
+		162,
+		164,
+		166,
+		168,
+		170,
+		172,
+		173,
+		175,
+		177,
+		179,
+		181,
+		182,
+		184,
+		186,
+		187,
+		189,
+		191,
+		192,
+		194,
+		196,
+		197,
+		199,
+		200,
+		202,
+		203,
+		205,
+		207,
+		208,
+		210,
+		211,
+		213,
+		214,
+		215,
+		217,
+		218,
+		220,
+		221,
+		223,
+		224,
+		225,
+		227,
+		228,
+		230,
+		231,
+		232,
+		234,
+		235,
+		236,
+		238,
+		239,
+		240,
+		242,
+		243,
+		244,
+		245,
+		247,
+		248,
+		249,
+		251,
+		252,
+		253,
+		254,
+		256,
+		257,
+		258,
+		259,
+		260,
+		262,
+		263,
+		264,
+		265,
+		266,
+		268,
+		269,
+		270,
+		271,
+		272,
+		273,
+		274,
+		276,
+		277,
+		278,
+		279,
+		280,
+		281,
+		282,
+		284,
+		285,
+		286,
+		287,
+		288,
+		289,
+		290,
+		291,
+		292,
+		293,
+		294,
+		295,
+		297,
+		298,
+		299,
+		300,
+		301,
+		302,
+		303,
+		304,
+		305,
+		306,
+		307,
+		308,
+		309,
+		310,
+		311,
+		312,
+		313,
+		314,
+		315,
+		316,
+		317,
+		318,
+		319,
+		320,
+		321,
+		322,
+		323,
+		324,
+		325,
+		326,
+		327,
+		328,
+		329,
+		330,
+		331,
+		332,
+		333,
+		333,
+		334,
+		335,
+		336,
+		337,
+		338,
+		339,
+		340,
+		341,
+		342,
+		343,
+		344,
+		345,
+		346,
+		346,
+		347,
+		348,
+		349,
+		350,
+		351,
+		352,
+		353,
+		354,
+		355,
+		355,
+		356,
+		357,
+		358,
+		359,
+		360,
+		361,
+		362,
+		362,
+		363,
+		364,
+		365,
+		366,
+		367,
+		368,
+		368,
+		369,
+		370,
+		371,
+		372,
+		373,
+		374,
+		374,
+		375,
+		376,
+		377,
+		378,
+		379,
+		379,
+		380,
+		381,
+		382,
+		383,
+		384,
+		384,
+		385,
+		386,
+		387,
+		388,
+		388,
+		389,
+		390,
+		391,
+		392,
+		392,
+		393,
+		394,
+		395,
+		396,
+		396,
+		397,
+		398,
+		399,
+		400,
+		400,
+		401,
+		402,
+		403,
+		403,
+		404,
+		405,
+		406,
+		407,
+		407,
+		408,
+		409,
+		410,
+		410,
+		411,
+		412,
+		413,
+		414,
+		414,
+		415,
+		416,
+		417,
+		417,
+		418,
+		419,
+		420,
+		420,
+		421,
+		422,
+		423,
+		423,
+		424,
+		425,
+		426,
+		426,
+		427,
+		428,
+		428,
+		429,
+		430,
+		431,
+		431,
+		432,
+		433,
+		434,
+		434,
+		435,
+		436,
+		436,
+		437,
+		438,
+		439,
+		439,
+		440,
+		441,
+		441,
+		442,
+		443,
+		444,
+		444,
+		445,
+		446,
+		446,
+		447,
+		448,
+		449,
+		449,
+		450,
+		451,
+		451,
+		452,
+		453,
+		453,
+		454,
+		455,
+		456,
+		456,
+		457,
+		458,
+		458,
+		459,
+		460,
+		460,
+		461,
+		462,
+		462,
+		463,
+		464,
+		464,
+		465,
+		466,
+		466,
+		467,
+		468,
+		468,
+		469,
+		470,
+		470,
+		471,
+		472,
+		472,
+		473,
+		474,
+		474,
+		475,
+		476,
+		476,
+		477,
+		478,
+		478,
+		479,
+		480,
+		480,
+		481,
+		482,
+		482,
+		483,
+		484,
+		484,
+		485,
+		486,
+		486,
+		487,
+		488,
+		488,
+		489,
+		489,
+		490,
+		491,
+		491,
+		492,
+		493,
+		493,
+		494,
+		495,
+		495,
+		496,
+		497,
+		497,
+		498,
+		498,
+	],
+};
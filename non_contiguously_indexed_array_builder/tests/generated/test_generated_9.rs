@@ -0,0 +1,62 @@
+use non_contiguously_indexed_array::NciRunArray;
+
+pub const GENERATED_9: NciRunArray<u32, u8> = NciRunArray {
+	runs_idx_begin: &[
+		0,
+		64,
+		128,
+		192,
+		250,
+		256,
+		320,
+		384,
+		448,
+		500,
+		512,
+		576,
+		640,
+		750,
+		768,
+		832,
+		896,
+	],
+	runs_entry_begin: &[
+		0,
+		64,
+		128,
+		192,
+		200,
+		206,
+		270,
+		334,
+		398,
+		400,
+		412,
+		476,
+		540,
+		600,
+		618,
+		682,
+		746,
+	],
+	len: 800,
+	values: &[
+		0,
+		1,
+		2,
+		3,
+		3,
+		4,
+		5,
+		6,
+		7,
+		7,
+		8,
+		9,
+		10,
+		11,
+		12,
+		13,
+		14,
+	],
+};
@@ -0,0 +1,167 @@
+//! Derive macro for the `NciIndex` trait of `non_contiguously_indexed_array`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DataEnum, DataStruct, DeriveInput, Error, Fields, parse_macro_input, parse_quote};
+
+/// Derives `NciIndex` for newtypes and fieldless enums.
+///
+/// For a struct with exactly one field, e.g., `struct UserId(u32)`, the implementation delegates to the field,
+/// so the struct has to implement `Ord` consistently with it, e.g., by deriving it.
+/// The field also has to implement `Debug`, as the struct is written as a Rust expression wrapping it.
+///
+/// For an enum without fields, e.g., `enum Opcode { Load, Store }`, the indices are the variants in declaration order.
+/// As the derived `Ord` orders variants by their discriminants, the discriminants are checked at compile time
+/// to be increasing in declaration order.
+#[proc_macro_derive(NciIndex)]
+pub fn derive_nci_index(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = match &input.data {
+        Data::Struct(data) => derive_for_struct(&input, data),
+        Data::Enum(data) => derive_for_enum(&input, data),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "`NciIndex` cannot be derived for unions",
+        )),
+    };
+    output.unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn derive_for_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2, Error> {
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`NciIndex` can only be derived for structs with exactly one field",
+        ));
+    };
+    let field_ty = &field.ty;
+    let ident = &input.ident;
+    let (field_access, constructor, expr_prefix, expr_suffix) = field.ident.as_ref().map_or_else(
+        || {
+            (
+                quote!(0),
+                quote!(|field| Self(field)),
+                format!("{ident}("),
+                ")",
+            )
+        },
+        |field_ident| {
+            (
+                quote!(#field_ident),
+                quote!(|field| Self { #field_ident: field }),
+                format!("{ident} {{ {field_ident}: "),
+                " }",
+            )
+        },
+    );
+
+    let mut generics = input.generics.clone();
+    generics.make_where_clause().predicates.push(parse_quote!(
+        #field_ty: ::non_contiguously_indexed_array::NciIndex + ::core::fmt::Debug
+    ));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::non_contiguously_indexed_array::NciIndex for #ident #ty_generics #where_clause {
+            fn next(self) -> ::core::option::Option<Self> {
+                ::non_contiguously_indexed_array::NciIndex::next(self.#field_access).map(#constructor)
+            }
+            fn distance(self, other: Self) -> ::core::option::Option<usize> {
+                ::non_contiguously_indexed_array::NciIndex::distance(
+                    self.#field_access,
+                    other.#field_access,
+                )
+            }
+            fn advance(self, n: usize) -> ::core::option::Option<Self> {
+                ::non_contiguously_indexed_array::NciIndex::advance(self.#field_access, n)
+                    .map(#constructor)
+            }
+            fn fmt_rust_expr(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(#expr_prefix)?;
+                ::non_contiguously_indexed_array::NciIndex::fmt_rust_expr(&self.#field_access, f)?;
+                f.write_str(#expr_suffix)
+            }
+        }
+    })
+}
+
+fn derive_for_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2, Error> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`NciIndex` cannot be derived for generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            ident,
+            "`NciIndex` cannot be derived for enums without variants",
+        ));
+    }
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(Error::new_spanned(
+            variant,
+            "`NciIndex` can only be derived for enums without fields",
+        ));
+    }
+
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let positions = 0..variants.len();
+    let position = |value: TokenStream2| {
+        let positions = positions.clone();
+        quote! {
+            match #value {
+                #(Self::#variants => #positions,)*
+            }
+        }
+    };
+    let self_position = position(quote!(self));
+    let other_position = position(quote!(other));
+    let variant_exprs = variants.iter().map(|variant| format!("{ident}::{variant}"));
+    let discriminant_checks = variants.windows(2).map(|pair| {
+        let (variant, next_variant) = (pair[0], pair[1]);
+        quote! {
+            ::core::assert!(
+                (#ident::#variant as i128) < (#ident::#next_variant as i128),
+                "`NciIndex` can only be derived for enums whose discriminants are increasing in declaration order",
+            );
+        }
+    });
+
+    Ok(quote! {
+        impl ::non_contiguously_indexed_array::NciIndex for #ident {
+            fn next(self) -> ::core::option::Option<Self> {
+                ::non_contiguously_indexed_array::NciIndex::advance(self, 1)
+            }
+            fn distance(self, other: Self) -> ::core::option::Option<usize> {
+                let position: usize = #self_position;
+                let other_position: usize = #other_position;
+                ::core::option::Option::Some(position.abs_diff(other_position))
+            }
+            fn advance(self, n: usize) -> ::core::option::Option<Self> {
+                const VARIANTS: &[#ident] = &[#(#ident::#variants),*];
+                let position: usize = #self_position;
+                position
+                    .checked_add(n)
+                    .and_then(|position| VARIANTS.get(position))
+                    .copied()
+            }
+            fn fmt_rust_expr(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match self {
+                    #(Self::#variants => #variant_exprs,)*
+                })
+            }
+        }
+
+        const _: () = {
+            #(#discriminant_checks)*
+        };
+    })
+}
@@ -0,0 +1,110 @@
+use std::num::NonZeroU8;
+
+// The trait and the derive macro are both imported through the re-export of the `derive` feature
+use non_contiguously_indexed_array::{NciArray, NciIndex};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, NciIndex)]
+struct UserId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, NciIndex)]
+struct Offset {
+    value: i8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, NciIndex)]
+struct Wrapper<T>(T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, NciIndex)]
+#[repr(u16)]
+enum Opcode {
+    Nop,
+    Load = 0x10,
+    Store,
+    Jump = 0x100,
+    Halt,
+}
+
+#[test]
+fn derive_test_newtype() {
+    assert_eq!(UserId(5).next(), Some(UserId(6)));
+    assert_eq!(UserId(u32::MAX).next(), None);
+    assert_eq!(UserId(5).distance(UserId(2)), Some(3));
+    assert_eq!(UserId(5).advance(10), Some(UserId(15)));
+    assert_eq!(UserId(u32::MAX - 1).advance(2), None);
+
+    assert_eq!(Offset { value: -1 }.next(), Some(Offset { value: 0 }));
+    assert_eq!(Offset { value: i8::MAX }.next(), None);
+    assert_eq!(
+        Offset { value: i8::MIN }.distance(Offset { value: i8::MAX }),
+        Some(255)
+    );
+    assert_eq!(Offset { value: -5 }.advance(10), Some(Offset { value: 5 }));
+
+    assert_eq!(Wrapper(7u8).advance(3), Some(Wrapper(10)));
+    assert_eq!(Wrapper(u8::MAX).next(), None);
+}
+
+#[test]
+fn derive_test_enum() {
+    assert_eq!(Opcode::Nop.next(), Some(Opcode::Load));
+    assert_eq!(Opcode::Store.next(), Some(Opcode::Jump));
+    assert_eq!(Opcode::Halt.next(), None);
+    assert_eq!(Opcode::Nop.distance(Opcode::Halt), Some(4));
+    assert_eq!(Opcode::Jump.distance(Opcode::Load), Some(2));
+    assert_eq!(Opcode::Load.advance(0), Some(Opcode::Load));
+    assert_eq!(Opcode::Load.advance(3), Some(Opcode::Halt));
+    assert_eq!(Opcode::Load.advance(4), None);
+    assert_eq!(Opcode::Halt.advance(usize::MAX), None);
+}
+
+#[test]
+fn derive_test_rust_expr() {
+    struct RustExpr<I>(I);
+
+    impl<I: NciIndex + std::fmt::Debug> std::fmt::Display for RustExpr<I> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.fmt_rust_expr(f)
+        }
+    }
+
+    assert_eq!(RustExpr(UserId(5)).to_string(), "UserId(5)");
+    assert_eq!(
+        RustExpr(Offset { value: -1 }).to_string(),
+        "Offset { value: -1 }"
+    );
+    assert_eq!(
+        RustExpr(Wrapper(NonZeroU8::new(3).unwrap())).to_string(),
+        "Wrapper(::core::num::NonZeroU8::new(3).unwrap())"
+    );
+    assert_eq!(RustExpr(Opcode::Store).to_string(), "Opcode::Store");
+}
+
+#[test]
+fn derive_test_array() {
+    const USER_ARRAY: NciArray<UserId, &str> = NciArray {
+        segments_idx_begin: &[UserId(1), UserId(100)],
+        segments_mem_idx_begin: &[0, 2],
+        values: &["root", "daemon", "user"],
+    };
+    assert_eq!(USER_ARRAY.get(UserId(2)), Some(&"daemon"));
+    assert_eq!(USER_ARRAY.get(UserId(3)), None);
+    assert_eq!(USER_ARRAY[UserId(100)], "user");
+    assert!(USER_ARRAY.indices().eq([UserId(1), UserId(2), UserId(100)]));
+
+    const OPCODE_ARRAY: NciArray<Opcode, u8> = NciArray {
+        segments_idx_begin: &[Opcode::Load, Opcode::Halt],
+        segments_mem_idx_begin: &[0, 2],
+        values: &[1, 2, 5],
+    };
+    assert!(OPCODE_ARRAY.validate().is_ok());
+    assert_eq!(OPCODE_ARRAY.get(Opcode::Nop), None);
+    assert_eq!(OPCODE_ARRAY.get(Opcode::Store), Some(&2));
+    assert_eq!(OPCODE_ARRAY.get(Opcode::Jump), None);
+    assert_eq!(OPCODE_ARRAY[Opcode::Halt], 5);
+    assert!(
+        OPCODE_ARRAY
+            .entries()
+            .map(|(index, value)| (index, *value))
+            .eq([(Opcode::Load, 1), (Opcode::Store, 2), (Opcode::Halt, 5)])
+    );
+}